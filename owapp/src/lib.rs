@@ -20,15 +20,12 @@ pub fn app() -> Html {
             temperature = Temperature::new(stored_temperature);
         }
         let mut relative_humidity = RelativeHumidity::default();
-        let stored_relative_humidity: Result<u8, _> =
+        let stored_relative_humidity: Result<f64, _> =
             LocalStorage::get("indoor_measurement_relative_humidity");
         if let Ok(stored_relative_humidity) = stored_relative_humidity {
-            relative_humidity = RelativeHumidity::new(stored_relative_humidity);
-        }
-        Measurement {
-            temperature,
-            relative_humidity,
+            relative_humidity = RelativeHumidity::from_percent(stored_relative_humidity);
         }
+        Measurement::new(temperature, relative_humidity)
     });
 
     let outdoor_measurement = use_state(|| {
@@ -39,24 +36,17 @@ pub fn app() -> Html {
             temperature = Temperature::new(stored_temperature);
         }
         let mut relative_humidity = RelativeHumidity::default();
-        let stored_relative_humidity: Result<u8, _> =
+        let stored_relative_humidity: Result<f64, _> =
             LocalStorage::get("outdoor_measurement_relative_humidity");
         if let Ok(stored_relative_humidity) = stored_relative_humidity {
-            relative_humidity = RelativeHumidity::new(stored_relative_humidity);
-        }
-        Measurement {
-            temperature,
-            relative_humidity,
+            relative_humidity = RelativeHumidity::from_percent(stored_relative_humidity);
         }
+        Measurement::new(temperature, relative_humidity)
     });
 
     let open_window_memo = use_memo(
         |(indoor_measurement, outdoor_measurement)| {
-            let open_window = open_window::open_window(indoor_measurement, outdoor_measurement);
-            if open_window {
-                return String::from("YES :)");
-            }
-            String::from("NO :(")
+            open_window::open_window_result(indoor_measurement, outdoor_measurement)
         },
         (indoor_measurement.clone(), outdoor_measurement.clone()),
     );
@@ -69,7 +59,7 @@ pub fn app() -> Html {
         );
         let _ = LocalStorage::set(
             "indoor_measurement_relative_humidity",
-            indoor_measurement.relative_humidity.value(),
+            indoor_measurement.relative_humidity.percent(),
         );
         Callback::from(move |measurement: Measurement| {
             indoor_measurement.set(measurement);
@@ -84,7 +74,7 @@ pub fn app() -> Html {
         );
         let _ = LocalStorage::set(
             "outdoor_measurement_relative_humidity",
-            outdoor_measurement.relative_humidity.value(),
+            outdoor_measurement.relative_humidity.percent(),
         );
         Callback::from(move |measurement: Measurement| {
             outdoor_measurement.set(measurement);
@@ -114,7 +104,11 @@ pub fn app() -> Html {
                     measurement={(*outdoor_measurement).clone()}
                     measurement_changed={outdoor_measurement_changed}
                 />
-                <h2>{open_window_memo}</h2>
+                <h2>{ if open_window_memo.open_window { "YES :)" } else { "NO :(" } }</h2>
+                <p>{ open_window_memo.verdict.message() }</p>
+                <ul>
+                    { for open_window_memo.reasons.iter().map(|reason| html! { <li>{ reason.message() }</li> }) }
+                </ul>
             </article>
         </main>
     }
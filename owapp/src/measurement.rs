@@ -1,7 +1,8 @@
 use owlib::open_window::{
-    measurement::Measurement, relative_humidity::RelativeHumidity, temperature::Temperature,
+    air_state::AirState, measurement::Measurement, relative_humidity::RelativeHumidity,
+    temperature::Temperature,
 };
-use yew::{function_component, html, use_state, Callback, Html, Properties};
+use yew::{function_component, html, use_memo, use_state, Callback, Html, Properties};
 
 use crate::{relative_humidity::RelativeHumidityComponent, temperature::TemperatureComponent};
 
@@ -25,15 +26,18 @@ pub fn MeasurementComponent(
 ) -> Html {
     let measurement_state = use_state(|| measurement.clone());
 
+    let air_state = use_memo(
+        |measurement| AirState::new(measurement.clone()),
+        (*measurement_state).clone(),
+    );
+
     let humidity_changed = {
         let measurement_state = measurement_state.clone();
         let measurement_changed = measurement_changed.clone();
 
         Callback::from(move |relative_humidity: RelativeHumidity| {
-            let measurement = Measurement {
-                temperature: measurement_state.temperature.clone(),
-                relative_humidity,
-            };
+            let mut measurement = (*measurement_state).clone();
+            measurement.relative_humidity = relative_humidity;
             measurement_state.set(measurement.clone());
             measurement_changed.emit(measurement);
         })
@@ -44,10 +48,8 @@ pub fn MeasurementComponent(
         let measurement_changed = measurement_changed.clone();
 
         Callback::from(move |temperature: Temperature| {
-            let measurement = Measurement {
-                temperature,
-                relative_humidity: measurement_state.relative_humidity.clone(),
-            };
+            let mut measurement = (*measurement_state).clone();
+            measurement.temperature = temperature;
             measurement_state.set(measurement.clone());
             measurement_changed.emit(measurement);
         })
@@ -66,7 +68,7 @@ pub fn MeasurementComponent(
             />
             <label>
                 { "Dew Point [°C]" }
-                <input type="number" disabled={true} value={format!("{:.2}", measurement_state.calculate_dew_point())}/>
+                <input type="number" disabled={true} value={format!("{:.2}", air_state.dew_point())}/>
             </label>
         </div>
     }
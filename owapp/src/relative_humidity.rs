@@ -1,9 +1,7 @@
 use owlib::open_window::relative_humidity::{RelativeHumidity, MAX_HUMIDITY, MIN_HUMIDITY};
 use yew::{function_component, html, use_state, Callback, Html, Properties};
 
-use crate::number_input::{Number, NumberInput};
-
-impl Number for u8 {}
+use crate::number_input::NumberInput;
 
 #[derive(Properties, PartialEq)]
 pub struct RelativeHumidityProps {
@@ -26,20 +24,20 @@ pub fn RelativeHumidityComponent(
         let relative_humidity_state = relative_humidity_state.clone();
         let humidity_changed = humidity_changed.clone();
 
-        Callback::from(move |number: u8| {
-            let relative_humidity = RelativeHumidity::new(number);
+        Callback::from(move |number: f64| {
+            let relative_humidity = RelativeHumidity::from_percent(number);
             relative_humidity_state.set(relative_humidity.clone());
             humidity_changed.emit(relative_humidity);
         })
     };
 
     html! {
-        <NumberInput<u8>
+        <NumberInput<f64>
             label="Relative Humidity [%]"
-            min={MIN_HUMIDITY}
-            max={MAX_HUMIDITY}
-            step={1u8}
-            value={relative_humidity_state.value()}
+            min={MIN_HUMIDITY as f64}
+            max={MAX_HUMIDITY as f64}
+            step={0.1}
+            value={relative_humidity_state.percent()}
             {number_changed}
         />
     }
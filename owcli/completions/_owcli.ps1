@@ -21,6 +21,8 @@ Register-ArgumentCompleter -Native -CommandName 'owcli' -ScriptBlock {
 
     $completions = @(switch ($command) {
         'owcli' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Algorithm deciding whether to open window')
+            [CompletionResult]::new('--strategy', 'strategy', [CompletionResultType]::ParameterName, 'Algorithm deciding whether to open window')
             [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Output JSON instead of human readable messages')
             [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Output JSON instead of human readable messages')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
@@ -19,12 +19,20 @@ _owcli() {
 
     case "${cmd}" in
         owcli)
-            opts="-j -h -V --json --help --version <INDOOR_TEMPERATURE> <INDOOR_HUMIDITY> <OUTDOOR_TEMPERATURE> <OUTDOOR_HUMIDITY>"
+            opts="-j -s -h -V --json --strategy --help --version <INDOOR_TEMPERATURE> <INDOOR_HUMIDITY> <OUTDOOR_TEMPERATURE> <OUTDOOR_HUMIDITY>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --strategy)
+                    COMPREPLY=($(compgen -W "dew_point absolute_humidity comfort energy" -- "${cur}"))
+                    return 0
+                    ;;
+                -s)
+                    COMPREPLY=($(compgen -W "dew_point absolute_humidity comfort energy" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -1,3 +1,4 @@
+complete -c owcli -s s -l strategy -d 'Algorithm deciding whether to open window' -r -f -a "{dew_point	'',absolute_humidity	'',comfort	'',energy	''}"
 complete -c owcli -s j -l json -d 'Output JSON instead of human readable messages'
 complete -c owcli -s h -l help -d 'Print help'
 complete -c owcli -s V -l version -d 'Print version'
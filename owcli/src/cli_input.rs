@@ -1,23 +1,33 @@
-use clap::Parser;
+use clap::{builder::PossibleValuesParser, Parser};
+use owlib::open_window::strategy::{DEFAULT_STRATEGY, STRATEGY_NAMES};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 pub struct CliInput {
-    #[arg()]
-    /// Indoor temperature in Celsius degrees
-    pub indoor_temperature: f64,
+    #[arg(allow_hyphen_values = true)]
+    /// Indoor temperature in Celsius degrees, unless followed by a unit (e.g. 64.4F, 291.15K)
+    pub indoor_temperature: String,
     #[arg()]
     /// Indoor relative humidity expressed as percentage (not fraction of 1)
-    pub indoor_humidity: u8,
+    pub indoor_humidity: f64,
 
-    #[arg()]
-    /// Outdoor temperature in Celsius degrees
-    pub outdoor_temperature: f64,
+    #[arg(allow_hyphen_values = true)]
+    /// Outdoor temperature in Celsius degrees, unless followed by a unit (e.g. 32F, 273.15K)
+    pub outdoor_temperature: String,
     #[arg()]
     /// Outdoor relative humidity expressed as percentage (not fraction of 1)
-    pub outdoor_humidity: u8,
+    pub outdoor_humidity: f64,
 
     #[arg(short, long)]
     /// Output JSON instead of human readable messages
     pub json: bool,
+
+    #[arg(
+        short,
+        long,
+        default_value = DEFAULT_STRATEGY,
+        value_parser = PossibleValuesParser::new(STRATEGY_NAMES)
+    )]
+    /// Algorithm deciding whether to open window
+    pub strategy: String,
 }
@@ -1,42 +1,35 @@
 mod cli_input;
-mod cli_output;
 
 use clap::Parser;
 use cli_input::CliInput;
-use cli_output::CliOutput;
 use human_panic::setup_panic;
 use owlib::open_window::measurement::Measurement;
-use owlib::open_window::open_window_result;
 use owlib::open_window::relative_humidity::RelativeHumidity;
+use owlib::open_window::strategy::{strategy_by_name, StrategyContext};
 use owlib::open_window::temperature::Temperature;
+use owlib::open_window::OpenWindowResult;
 use std::error::Error;
 
-fn run() -> Result<(CliOutput, bool), Box<dyn Error>> {
+fn run() -> Result<(OpenWindowResult, bool), Box<dyn Error>> {
     let args = CliInput::parse();
 
-    let indoor_humidity = RelativeHumidity::try_new(args.indoor_humidity)?;
-    let indoor_temperature = Temperature::try_new(args.indoor_temperature)?;
-    let indoor_measurement = Measurement {
-        temperature: indoor_temperature,
-        relative_humidity: indoor_humidity,
-    };
+    let indoor_humidity = RelativeHumidity::try_from_percent(args.indoor_humidity)?;
+    let indoor_temperature = args.indoor_temperature.parse::<Temperature>()?;
+    let indoor_measurement = Measurement::new(indoor_temperature, indoor_humidity);
 
-    let outdoor_humidity = RelativeHumidity::try_new(args.outdoor_humidity)?;
-    let outdoor_temperature = Temperature::try_new(args.outdoor_temperature)?;
-    let outdoor_measurement = Measurement {
-        temperature: outdoor_temperature,
-        relative_humidity: outdoor_humidity,
-    };
+    let outdoor_humidity = RelativeHumidity::try_from_percent(args.outdoor_humidity)?;
+    let outdoor_temperature = args.outdoor_temperature.parse::<Temperature>()?;
+    let outdoor_measurement = Measurement::new(outdoor_temperature, outdoor_humidity);
 
-    let open_window_result = open_window_result(&indoor_measurement, &outdoor_measurement);
+    let strategy = strategy_by_name(&args.strategy)
+        .ok_or_else(|| format!("unknown strategy: {}", args.strategy))?;
+    let open_window_result = strategy.evaluate(
+        &indoor_measurement,
+        &outdoor_measurement,
+        &StrategyContext::default(),
+    );
 
-    let cli_output = CliOutput {
-        indoor_dew_point: open_window_result.indoor_dew_point,
-        outdoor_dew_point: open_window_result.outdoor_dew_point,
-        open_window: open_window_result.open_window,
-    };
-
-    Ok((cli_output, args.json))
+    Ok((open_window_result, args.json))
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -50,11 +43,10 @@ fn main() -> Result<(), Box<dyn Error>> {
             } else {
                 println!("Indoor dew point: {:.2}", output.indoor_dew_point);
                 println!("Outdoor dew point: {:.2}", output.outdoor_dew_point);
-                let message = match output.open_window {
-                    true => "Open window!",
-                    false => "Close window!",
-                };
-                println!("{message}");
+                println!("{}", output.verdict);
+                for reason in output.reasons {
+                    println!("- {reason}");
+                }
             }
             std::process::exit(0)
         }
@@ -25,3 +25,148 @@ fn close_window_test() {
         .code(0)
         .stdout(predicate::str::contains("Close window!"));
 }
+
+#[test]
+fn fahrenheit_test() {
+    let mut cmd = Command::cargo_bin("owcli").unwrap();
+
+    let assert = cmd.arg("68F").arg("50").arg("32F").arg("95").assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("Open window!"));
+}
+
+#[test]
+fn negative_temperature_test() {
+    let mut cmd = Command::cargo_bin("owcli").unwrap();
+
+    let assert = cmd.arg("20").arg("50").arg("-5").arg("80").assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("Open window!"));
+}
+
+#[test]
+fn invalid_temperature_test() {
+    let mut cmd = Command::cargo_bin("owcli").unwrap();
+
+    let assert = cmd.arg("300F").arg("50").arg("0").arg("95").assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("temperature must be"));
+}
+
+#[test]
+fn json_test() {
+    let mut cmd = Command::cargo_bin("owcli").unwrap();
+
+    let assert = cmd
+        .arg("20")
+        .arg("50")
+        .arg("0")
+        .arg("95")
+        .arg("--json")
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains(r#""verdict":"open""#))
+        .stdout(predicate::str::contains(
+            r#""reasons":["outdoor_drier","condensation_risk"]"#,
+        ));
+}
+
+#[test]
+fn json_layout_test() {
+    let mut cmd = Command::cargo_bin("owcli").unwrap();
+
+    let output = cmd
+        .arg("20")
+        .arg("50")
+        .arg("0")
+        .arg("95")
+        .arg("--json")
+        .output()
+        .unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let keys: Vec<&str> = json
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+
+    assert_eq!(
+        vec![
+            "confidence",
+            "indoor_dew_point",
+            "open_window",
+            "outdoor_dew_point",
+            "reasons",
+            "verdict"
+        ],
+        keys
+    );
+    assert_eq!("9.26", format!("{:.2}", json["indoor_dew_point"].as_f64().unwrap()));
+    assert_eq!("-0.71", format!("{:.2}", json["outdoor_dew_point"].as_f64().unwrap()));
+    assert_eq!(true, json["open_window"]);
+    assert_eq!("certain", json["confidence"]);
+}
+
+#[test]
+fn fractional_humidity_test() {
+    let mut cmd = Command::cargo_bin("owcli").unwrap();
+
+    let assert = cmd.arg("18").arg("55.4").arg("10").arg("88.5").assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("Indoor dew point: 8.93"));
+}
+
+#[test]
+fn strategy_test() {
+    let mut cmd = Command::cargo_bin("owcli").unwrap();
+
+    let assert = cmd
+        .arg("18")
+        .arg("55")
+        .arg("10")
+        .arg("88")
+        .arg("--strategy")
+        .arg("energy")
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("No need to open window."))
+        .stdout(predicate::str::contains(
+            "Airing would waste too much heat.",
+        ));
+}
+
+#[test]
+fn unknown_strategy_test() {
+    let mut cmd = Command::cargo_bin("owcli").unwrap();
+
+    let assert = cmd
+        .arg("18")
+        .arg("55")
+        .arg("10")
+        .arg("88")
+        .arg("--strategy")
+        .arg("unknown")
+        .assert();
+
+    assert.failure().code(2);
+}
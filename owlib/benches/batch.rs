@@ -0,0 +1,116 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use owlib::open_window::{
+    batch::{dew_points, open_window_batch, MeasurementSeries},
+    dew_point::DewPointFormula,
+    measurement::Measurement,
+    open_window_result,
+    relative_humidity::RelativeHumidity,
+    temperature::Temperature,
+    verdict::Verdict,
+};
+
+/// One week of one-minute readings.
+const SAMPLES: usize = 7 * 24 * 60;
+
+fn series(offset: f64) -> (Vec<f64>, Vec<f64>) {
+    (0..SAMPLES)
+        .map(|i| {
+            let phase = i as f64 / 60.0;
+            (
+                offset + 5.0 * phase.sin(),
+                60.0 + 30.0 * (phase / 3.0).cos(),
+            )
+        })
+        .unzip()
+}
+
+fn measurements(temperatures: &[f64], relative_humidities: &[f64]) -> Vec<Measurement> {
+    temperatures
+        .iter()
+        .zip(relative_humidities)
+        .map(|(&temperature, &relative_humidity)| {
+            Measurement::new(
+                Temperature::new(temperature),
+                RelativeHumidity::from_percent(relative_humidity),
+            )
+        })
+        .collect()
+}
+
+fn open_window(c: &mut Criterion) {
+    let (indoor_temperatures, indoor_relative_humidities) = series(20.0);
+    let (outdoor_temperatures, outdoor_relative_humidities) = series(5.0);
+    let indoor_measurements = measurements(&indoor_temperatures, &indoor_relative_humidities);
+    let outdoor_measurements = measurements(&outdoor_temperatures, &outdoor_relative_humidities);
+
+    let mut group = c.benchmark_group("open_window");
+
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            indoor_measurements
+                .iter()
+                .zip(&outdoor_measurements)
+                .map(|(indoor, outdoor)| open_window_result(black_box(indoor), black_box(outdoor)))
+                .filter(|result| result.open_window)
+                .count()
+        })
+    });
+
+    let indoor = MeasurementSeries::new(&indoor_temperatures, &indoor_relative_humidities).unwrap();
+    let outdoor =
+        MeasurementSeries::new(&outdoor_temperatures, &outdoor_relative_humidities).unwrap();
+    let mut indoor_dew_points = vec![0.0; SAMPLES];
+    let mut outdoor_dew_points = vec![0.0; SAMPLES];
+    let mut verdicts = vec![Verdict::Close; SAMPLES];
+
+    group.bench_function("batch", |b| {
+        b.iter(|| {
+            open_window_batch(
+                black_box(&indoor),
+                black_box(&outdoor),
+                &mut indoor_dew_points,
+                &mut outdoor_dew_points,
+                &mut verdicts,
+            );
+            verdicts
+                .iter()
+                .filter(|&&verdict| verdict == Verdict::Open)
+                .count()
+        })
+    });
+
+    group.finish();
+}
+
+fn dew_point(c: &mut Criterion) {
+    let (temperatures, relative_humidities) = series(20.0);
+    let formula = DewPointFormula::default();
+    let mut output = vec![0.0; SAMPLES];
+
+    let mut group = c.benchmark_group("dew_point");
+
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for ((dew_point, &temperature), &relative_humidity) in output
+                .iter_mut()
+                .zip(black_box(&temperatures))
+                .zip(black_box(&relative_humidities))
+            {
+                *dew_point = formula.dew_point(
+                    relative_humidity / 100.0 * formula.saturation_vapour_pressure(temperature),
+                );
+            }
+        })
+    });
+
+    let series = MeasurementSeries::new(&temperatures, &relative_humidities).unwrap();
+
+    group.bench_function("batch", |b| {
+        b.iter(|| dew_points(black_box(&series), &mut output))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, open_window, dew_point);
+criterion_main!(benches);
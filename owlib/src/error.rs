@@ -0,0 +1,351 @@
+use core::fmt;
+
+/// A quantity which failed validation.
+///
+/// More fields may be added as the library grows, so matches on it need
+/// a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Field {
+    /// Temperature expressed in Celsius degrees (°C).
+    Temperature,
+    /// Relative humidity expressed as percentage (%).
+    RelativeHumidity,
+    /// Barometric pressure expressed in hectopascals (hPa).
+    Pressure,
+    /// Room volume expressed in cubic metres (m³).
+    Volume,
+    /// Number of air changes per hour.
+    AirChangesPerHour,
+    /// Moisture sources expressed in grams per hour (g/h).
+    MoistureSources,
+    /// Difference between dew points expressed in Celsius degrees (°C).
+    DewPointDelta,
+    /// Width of a hysteresis band expressed in Celsius degrees (°C).
+    Hysteresis,
+    /// Temperature accuracy of a sensor expressed in Celsius degrees (±°C).
+    TemperatureTolerance,
+    /// Relative humidity accuracy of a sensor expressed in percentage points (±%).
+    RelativeHumidityTolerance,
+    /// Thermal transmittance (U-value) expressed in W/(m²·K).
+    UValue,
+    /// Temperature factor at an internal surface (fRsi).
+    TemperatureFactor,
+    /// Wet-bulb temperature expressed in Celsius degrees (°C).
+    WetBulbTemperature,
+}
+
+impl Field {
+    /// Returns a human-readable name of the field.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Temperature => "temperature",
+            Self::RelativeHumidity => "relative humidity",
+            Self::Pressure => "pressure",
+            Self::Volume => "volume",
+            Self::AirChangesPerHour => "air changes per hour",
+            Self::MoistureSources => "moisture sources",
+            Self::DewPointDelta => "dew point delta",
+            Self::Hysteresis => "hysteresis",
+            Self::TemperatureTolerance => "temperature tolerance",
+            Self::RelativeHumidityTolerance => "relative humidity tolerance",
+            Self::UValue => "U-value",
+            Self::TemperatureFactor => "temperature factor",
+            Self::WetBulbTemperature => "wet-bulb temperature",
+        }
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An error returned if a value does not pass validation, e.g. by
+/// [Temperature::try_new](crate::open_window::temperature::Temperature::try_new).
+///
+/// Every variant holds the validated field, most of them also its allowed
+/// range (`min..=max`, or at least `min` for fields without an upper bound). More variants may be added in the future.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Value is below the allowed minimum.
+    BelowMinimum {
+        /// Validated field.
+        field: Field,
+        /// Provided value.
+        value: f64,
+        /// Minimum allowed value.
+        min: f64,
+        /// Maximum allowed value, `None` if the field has no upper bound.
+        max: Option<f64>,
+    },
+    /// Value is above the allowed maximum.
+    AboveMaximum {
+        /// Validated field.
+        field: Field,
+        /// Provided value.
+        value: f64,
+        /// Minimum allowed value.
+        min: f64,
+        /// Maximum allowed value.
+        max: f64,
+    },
+    /// Value is not a number.
+    NaN {
+        /// Validated field.
+        field: Field,
+        /// Minimum allowed value.
+        min: f64,
+        /// Maximum allowed value, `None` if the field has no upper bound.
+        max: Option<f64>,
+    },
+    /// Value is infinite.
+    Infinite {
+        /// Validated field.
+        field: Field,
+        /// Provided value - positive or negative infinity.
+        value: f64,
+        /// Minimum allowed value.
+        min: f64,
+        /// Maximum allowed value, `None` if the field has no upper bound.
+        max: Option<f64>,
+    },
+    /// Water boils at the provided temperature, given barometric pressure, so
+    /// there is no dry air left.
+    AboveBoilingPoint {
+        /// Validated field.
+        field: Field,
+        /// Provided value.
+        value: f64,
+        /// Barometric pressure expressed in hectopascals (hPa).
+        pressure: f64,
+    },
+    /// Number of values does not match the number of values they are paired with.
+    LengthMismatch {
+        /// Validated field.
+        field: Field,
+        /// Expected number of values.
+        expected: usize,
+        /// Provided number of values.
+        actual: usize,
+    },
+}
+
+impl Error {
+    /// Checks whether provided value of the field falls within `min..=max` range.
+    pub(crate) fn validate(field: Field, value: f64, min: f64, max: f64) -> Result<f64, Self> {
+        Self::validate_range(field, value, min, Some(max))
+    }
+
+    /// Checks whether provided value of the field is a finite number of at
+    /// least `min`.
+    pub(crate) fn validate_min(field: Field, value: f64, min: f64) -> Result<f64, Self> {
+        Self::validate_range(field, value, min, None)
+    }
+
+    fn validate_range(field: Field, value: f64, min: f64, max: Option<f64>) -> Result<f64, Self> {
+        if value.is_nan() {
+            Err(Self::NaN { field, min, max })
+        } else if value.is_infinite() {
+            Err(Self::Infinite {
+                field,
+                value,
+                min,
+                max,
+            })
+        } else if value < min {
+            Err(Self::BelowMinimum {
+                field,
+                value,
+                min,
+                max,
+            })
+        } else {
+            match max {
+                Some(max) if value > max => Err(Self::AboveMaximum {
+                    field,
+                    value,
+                    min,
+                    max,
+                }),
+                _ => Ok(value),
+            }
+        }
+    }
+
+    /// Returns the field which failed validation.
+    pub fn field(&self) -> Field {
+        match self {
+            Self::BelowMinimum { field, .. }
+            | Self::AboveMaximum { field, .. }
+            | Self::NaN { field, .. }
+            | Self::Infinite { field, .. }
+            | Self::AboveBoilingPoint { field, .. }
+            | Self::LengthMismatch { field, .. } => *field,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BelowMinimum {
+                field, value, min, ..
+            } => write!(f, "{field} must be at least {min}, got {value}"),
+            Self::AboveMaximum {
+                field, value, max, ..
+            } => write!(f, "{field} must be at most {max}, got {value}"),
+            Self::NaN { field, min, max } => {
+                write!(f, "{field} must be a number {}", Range(*min, *max))
+            }
+            Self::Infinite {
+                field,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "{field} must be a finite number {}, got {value}",
+                Range(*min, *max)
+            ),
+            Self::AboveBoilingPoint {
+                field,
+                value,
+                pressure,
+            } => write!(
+                f,
+                "{field} must be below the boiling point at {pressure} hPa, got {value}"
+            ),
+            Self::LengthMismatch {
+                field,
+                expected,
+                actual,
+            } => write!(f, "{field} must have {expected} values, got {actual}"),
+        }
+    }
+}
+
+/// Formats an allowed range of values.
+struct Range(f64, Option<f64>);
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self(min, Some(max)) => write!(f, "between {min} and {max}"),
+            Self(min, None) => write!(f, "of at least {min}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Field};
+
+    macro_rules! validate_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (value, expected) = $value;
+
+                    let result = Error::validate(Field::Temperature, value, -100.0, 100.0);
+
+                    assert_eq!(expected, result);
+                }
+             )*
+        }
+    }
+
+    validate_tests! {
+        valid: (18.0, Ok(18.0)),
+        valid_min: (-100.0, Ok(-100.0)),
+        valid_max: (100.0, Ok(100.0)),
+        below_minimum: (
+            -100.5,
+            Err(Error::BelowMinimum {
+                field: Field::Temperature,
+                value: -100.5,
+                min: -100.0,
+                max: Some(100.0),
+            })
+        ),
+        above_maximum: (
+            150.0,
+            Err(Error::AboveMaximum {
+                field: Field::Temperature,
+                value: 150.0,
+                min: -100.0,
+                max: 100.0,
+            })
+        ),
+        nan: (
+            f64::NAN,
+            Err(Error::NaN { field: Field::Temperature, min: -100.0, max: Some(100.0) })
+        ),
+        infinite: (
+            f64::NEG_INFINITY,
+            Err(Error::Infinite {
+                field: Field::Temperature,
+                value: f64::NEG_INFINITY,
+                min: -100.0,
+                max: Some(100.0),
+            })
+        ),
+    }
+
+    macro_rules! display_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let ((field, value, min, max), expected) = $value;
+
+                    let error = match max {
+                        Some(max) => Error::validate(field, value, min, max),
+                        None => Error::validate_min(field, value, min),
+                    }
+                    .unwrap_err();
+
+                    assert_eq!(field, error.field());
+                    assert_eq!(expected, error.to_string());
+                }
+             )*
+        }
+    }
+
+    display_tests! {
+        display_below_minimum: (
+            (Field::RelativeHumidity, 0.5, 1.0, Some(100.0)),
+            "relative humidity must be at least 1, got 0.5"
+        ),
+        display_above_maximum: (
+            (Field::Pressure, 1200.0, 300.0, Some(1100.0)),
+            "pressure must be at most 1100, got 1200"
+        ),
+        display_nan: (
+            (Field::Temperature, f64::NAN, -100.0, Some(100.0)),
+            "temperature must be a number between -100 and 100"
+        ),
+        display_infinite: (
+            (Field::Temperature, f64::INFINITY, -100.0, Some(100.0)),
+            "temperature must be a finite number between -100 and 100, got inf"
+        ),
+        display_nan_unbounded: (
+            (Field::Volume, f64::NAN, 1.0, None),
+            "volume must be a number of at least 1"
+        ),
+        display_infinite_unbounded: (
+            (Field::Volume, f64::INFINITY, 1.0, None),
+            "volume must be a finite number of at least 1, got inf"
+        ),
+        display_below_minimum_unbounded: (
+            (Field::Volume, 0.0, 1.0, None),
+            "volume must be at least 1, got 0"
+        ),
+    }
+}
@@ -1,4 +1,5 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 //! Answers a question whether one should open windows or not in order to decrease
 //! indoor humidity.
@@ -16,20 +17,39 @@
 //!
 //! let indoor_temperature = Temperature::new(18.0);
 //! let indoor_humidity = RelativeHumidity::new(55);
-//! let indoor_measurement = Measurement {
-//!     temperature: indoor_temperature,
-//!     relative_humidity: indoor_humidity,
-//! };
+//! let indoor_measurement = Measurement::new(indoor_temperature, indoor_humidity);
 //!
 //! let outdoor_temperature = Temperature::new(1.0);
 //! let outdoor_humidity = RelativeHumidity::new(85);
-//! let outdoor_measurement = Measurement {
-//!     temperature: outdoor_temperature,
-//!     relative_humidity: outdoor_humidity,
-//! };
+//! let outdoor_measurement = Measurement::new(outdoor_temperature, outdoor_humidity);
 //!
 //! let open_window = open_window(&indoor_measurement, &outdoor_measurement);
+//! ```
+//!
+//! # `no_std`
+//!
+//! The library is `no_std` compatible (it still requires `alloc`). Disable
+//! default `std` feature and enable `libm` feature in order to use it on
+//! microcontrollers - floating point functions are then provided by `libm`:
+//!
+//! ```toml
+//! owlib = { version = "0.1", default-features = false, features = ["libm"] }
+//! ```
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("owlib requires either `std` or `libm` feature to be enabled");
+
+extern crate alloc;
+
+mod error;
+mod math;
+
+pub use error::{Error, Field};
 
 /// Module holds necessary structures and functions to calculate dew points and
 /// answer the "should you open windows" question.
 pub mod open_window;
+
+/// Module holds functions calculating psychrometric properties of moist air
+/// (vapour pressure, absolute humidity, enthalpy etc.) from a `Measurement`.
+pub mod psychrometrics;
@@ -0,0 +1,62 @@
+//! Floating point functions that are not available in `core`. They resolve to
+//! inherent `f64` methods when `std` feature is enabled and to `libm` otherwise.
+
+#[cfg(feature = "std")]
+pub(crate) fn abs(x: f64) -> f64 {
+    x.abs()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn abs(x: f64) -> f64 {
+    libm::fabs(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn exp(x: f64) -> f64 {
+    x.exp()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    x.powf(y)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    libm::pow(x, y)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn round(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn round(x: f64) -> f64 {
+    libm::round(x)
+}
@@ -0,0 +1,269 @@
+use core::cell::OnceCell;
+
+use crate::psychrometrics::{
+    absolute_humidity_at, air_density_at, enthalpy_at, humidity_ratio_at, pressure,
+    saturation_vapour_pressure_at, specific_humidity_at, wet_bulb_temperature_at,
+};
+
+use super::{dew_point::DewPointFormula, measurement::Measurement};
+
+/// Holds a `Measurement` together with lazily calculated properties of the
+/// measured air.
+///
+/// Each property is calculated on first access and cached, so an `AirState`
+/// can be passed around and queried repeatedly, without recalculating
+/// logarithms and exponents. Properties depending on each other share
+/// intermediate results, e.g. vapour pressure is calculated once for the dew
+/// point, absolute humidity and the remaining quantities.
+///
+/// Values are the same as returned by [Measurement::calculate_dew_point] and
+/// functions of [crate::psychrometrics] module.
+///
+/// # Example
+///
+/// ```
+/// use owlib::open_window::air_state::AirState;
+/// use owlib::open_window::measurement::Measurement;
+/// use owlib::open_window::relative_humidity::RelativeHumidity;
+/// use owlib::open_window::temperature::Temperature;
+///
+/// let air_state =
+///     AirState::new(Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55)));
+///
+/// assert_eq!("8.82", format!("{:.2}", air_state.dew_point()));
+/// assert_eq!("8.43", format!("{:.2}", air_state.absolute_humidity()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AirState {
+    measurement: Measurement,
+    saturation_vapour_pressure: OnceCell<f64>,
+    vapour_pressure: OnceCell<f64>,
+    dew_point: OnceCell<f64>,
+    absolute_humidity: OnceCell<f64>,
+    humidity_ratio: OnceCell<Option<f64>>,
+    specific_humidity: OnceCell<Option<f64>>,
+    air_density: OnceCell<Option<f64>>,
+    enthalpy: OnceCell<Option<f64>>,
+    wet_bulb_temperature: OnceCell<Option<f64>>,
+}
+
+impl AirState {
+    /// Creates a new `AirState` struct. No properties are calculated until accessed.
+    pub fn new(measurement: Measurement) -> Self {
+        Self {
+            measurement,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the measurement the properties are calculated from.
+    pub fn measurement(&self) -> &Measurement {
+        &self.measurement
+    }
+
+    /// Returns saturation vapour pressure over water (hPa) at measured temperature.
+    pub fn saturation_vapour_pressure(&self) -> f64 {
+        *self
+            .saturation_vapour_pressure
+            .get_or_init(|| saturation_vapour_pressure_at(self.temperature()))
+    }
+
+    /// Returns actual (partial) vapour pressure (hPa) of the measured air.
+    pub fn vapour_pressure(&self) -> f64 {
+        *self.vapour_pressure.get_or_init(|| {
+            self.measurement.relative_humidity.percent() / 100.0 * self.saturation_vapour_pressure()
+        })
+    }
+
+    /// Returns a dew point (°C), calculated with [DewPointFormula::AlduchovEskridge].
+    pub fn dew_point(&self) -> f64 {
+        *self
+            .dew_point
+            .get_or_init(|| DewPointFormula::default().dew_point(self.vapour_pressure()))
+    }
+
+    /// Returns absolute humidity expressed in g/m³.
+    pub fn absolute_humidity(&self) -> f64 {
+        *self
+            .absolute_humidity
+            .get_or_init(|| absolute_humidity_at(self.vapour_pressure(), self.temperature()))
+    }
+
+    /// Returns humidity ratio (mixing ratio) expressed in g/kg, or `None` if
+    /// vapour pressure is not below barometric pressure.
+    pub fn humidity_ratio(&self) -> Option<f64> {
+        self.dry_air_humidity_ratio()
+            .map(|humidity_ratio| humidity_ratio * 1000.0)
+    }
+
+    /// Returns specific humidity expressed in g/kg, or `None` if vapour
+    /// pressure is not below barometric pressure.
+    pub fn specific_humidity(&self) -> Option<f64> {
+        self.specific_humidity
+            .get_or_init(|| specific_humidity_at(self.vapour_pressure(), self.pressure()))
+            .map(|specific_humidity| specific_humidity * 1000.0)
+    }
+
+    /// Returns density of the measured (moist) air expressed in kg/m³, or
+    /// `None` if vapour pressure is not below barometric pressure.
+    pub fn air_density(&self) -> Option<f64> {
+        *self.air_density.get_or_init(|| {
+            air_density_at(self.vapour_pressure(), self.pressure(), self.temperature())
+        })
+    }
+
+    /// Returns specific enthalpy expressed in kJ per kg of dry air, or `None`
+    /// if vapour pressure is not below barometric pressure.
+    pub fn enthalpy(&self) -> Option<f64> {
+        *self.enthalpy.get_or_init(|| {
+            self.dry_air_humidity_ratio()
+                .map(|humidity_ratio| enthalpy_at(humidity_ratio, self.temperature()))
+        })
+    }
+
+    /// Returns thermodynamic wet-bulb temperature (°C), or `None` if vapour
+    /// pressure is not below barometric pressure.
+    pub fn wet_bulb_temperature(&self) -> Option<f64> {
+        *self.wet_bulb_temperature.get_or_init(|| {
+            self.dry_air_humidity_ratio().map(|humidity_ratio| {
+                wet_bulb_temperature_at(
+                    humidity_ratio,
+                    self.pressure(),
+                    self.temperature(),
+                    self.dew_point(),
+                )
+            })
+        })
+    }
+
+    fn temperature(&self) -> f64 {
+        self.measurement.temperature.value()
+    }
+
+    fn pressure(&self) -> f64 {
+        pressure(&self.measurement)
+    }
+
+    /// Humidity ratio expressed in kg/kg, as used by the remaining formulas.
+    fn dry_air_humidity_ratio(&self) -> Option<f64> {
+        *self
+            .humidity_ratio
+            .get_or_init(|| humidity_ratio_at(self.vapour_pressure(), self.pressure()))
+    }
+}
+
+impl From<Measurement> for AirState {
+    fn from(measurement: Measurement) -> Self {
+        Self::new(measurement)
+    }
+}
+
+/// Compares measurements only, regardless of which properties were already calculated.
+impl PartialEq for AirState {
+    fn eq(&self, other: &Self) -> bool {
+        self.measurement == other.measurement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        open_window::{
+            measurement::Measurement, pressure::Pressure, relative_humidity::RelativeHumidity,
+            temperature::Temperature,
+        },
+        psychrometrics,
+    };
+
+    use super::AirState;
+
+    macro_rules! air_state_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (temperature, relative_humidity, altitude) = $value;
+                    let measurement = Measurement::new(
+                        Temperature::new(temperature),
+                        RelativeHumidity::new(relative_humidity),
+                    );
+                    let measurement = match altitude {
+                        Some(altitude) => measurement
+                            .with_pressure(Pressure::from_altitude(altitude).unwrap()),
+                        None => measurement,
+                    };
+
+                    let air_state = AirState::new(measurement.clone());
+
+                    assert_eq!(measurement.calculate_dew_point(), air_state.dew_point());
+                    assert_eq!(
+                        psychrometrics::saturation_vapour_pressure(&measurement.temperature),
+                        air_state.saturation_vapour_pressure()
+                    );
+                    assert_eq!(
+                        psychrometrics::vapour_pressure(&measurement),
+                        air_state.vapour_pressure()
+                    );
+                    assert_eq!(
+                        psychrometrics::absolute_humidity(&measurement),
+                        air_state.absolute_humidity()
+                    );
+                    assert_eq!(
+                        psychrometrics::humidity_ratio(&measurement),
+                        air_state.humidity_ratio()
+                    );
+                    assert_eq!(
+                        psychrometrics::specific_humidity(&measurement),
+                        air_state.specific_humidity()
+                    );
+                    assert_eq!(
+                        psychrometrics::air_density(&measurement),
+                        air_state.air_density()
+                    );
+                    assert_eq!(psychrometrics::enthalpy(&measurement), air_state.enthalpy());
+                    assert_eq!(
+                        psychrometrics::wet_bulb_temperature(&measurement),
+                        air_state.wet_bulb_temperature()
+                    );
+                }
+             )*
+        }
+    }
+
+    air_state_tests! {
+        indoor: (18.0, 55, None),
+        outdoor: (-5.0, 80, None),
+        hot: (30.0, 70, None),
+        altitude: (18.0, 55, Some(1500.0)),
+    }
+
+    #[test]
+    fn cached_value_is_reused() {
+        let air_state = AirState::from(Measurement::new(
+            Temperature::new(18.0),
+            RelativeHumidity::new(55),
+        ));
+
+        let dew_point = air_state.dew_point();
+
+        assert_eq!(Some(&dew_point), air_state.dew_point.get());
+        assert!(air_state.wet_bulb_temperature.get().is_none());
+        assert_eq!(dew_point, air_state.dew_point());
+    }
+
+    #[test]
+    fn equality_ignores_cache() {
+        let measurement = Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+        let calculated = AirState::new(measurement.clone());
+        calculated.enthalpy();
+
+        assert_eq!(AirState::new(measurement.clone()), calculated);
+        assert_ne!(
+            AirState::new(Measurement::new(
+                Temperature::new(18.0),
+                RelativeHumidity::new(60)
+            )),
+            calculated
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{Error, Field};
+
+use super::{dew_point::default_dew_points, verdict::Verdict};
+
+/// Holds a series of measurements in a columnar layout - temperatures (°C) and
+/// relative humidities (%) at the same indices belong to the same measurement.
+///
+/// Values are not validated, as opposed to [Temperature](super::temperature::Temperature)
+/// and [RelativeHumidity](super::relative_humidity::RelativeHumidity). Values
+/// outside of their allowed ranges yield meaningless results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasurementSeries<'a> {
+    temperatures: &'a [f64],
+    relative_humidities: &'a [f64],
+}
+
+impl<'a> MeasurementSeries<'a> {
+    /// Creates a new `MeasurementSeries` struct.
+    ///
+    /// Returns an [Error] if there is a different number of temperatures and
+    /// relative humidities.
+    pub fn new(temperatures: &'a [f64], relative_humidities: &'a [f64]) -> Result<Self, Error> {
+        if temperatures.len() != relative_humidities.len() {
+            return Err(Error::LengthMismatch {
+                field: Field::RelativeHumidity,
+                expected: temperatures.len(),
+                actual: relative_humidities.len(),
+            });
+        }
+
+        Ok(Self {
+            temperatures,
+            relative_humidities,
+        })
+    }
+
+    /// Returns temperatures (°C).
+    pub fn temperatures(&self) -> &'a [f64] {
+        self.temperatures
+    }
+
+    /// Returns relative humidities (%).
+    pub fn relative_humidities(&self) -> &'a [f64] {
+        self.relative_humidities
+    }
+
+    /// Returns a number of measurements in the series.
+    pub fn len(&self) -> usize {
+        self.temperatures.len()
+    }
+
+    /// Returns `true` if the series holds no measurements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Calculates dew points of all measurements in the series and writes them into
+/// `dew_points`.
+///
+/// Uses Magnus formula with Alduchov-Eskridge coefficients, the same as
+/// [Measurement::calculate_dew_point](super::measurement::Measurement::calculate_dew_point).
+///
+/// Logarithms of relative humidities are calculated in a pass of their own and
+/// the rest of the formula in a branch-free pass, which the compiler vectorises.
+/// It also skips validation, sensor tolerances and allocation of reasons, and
+/// calculates a dew point in one step instead of an `exp` and `ln` round trip.
+///
+/// # Panics
+///
+/// Panics if `dew_points` length differs from the series length.
+pub fn dew_points(series: &MeasurementSeries, dew_points: &mut [f64]) {
+    assert_eq!(
+        series.len(),
+        dew_points.len(),
+        "dew points must have the same length as the series"
+    );
+
+    default_dew_points(series.temperatures, series.relative_humidities, dew_points);
+}
+
+/// Answers "open window" question for every pair of indoor and outdoor
+/// measurements in the series, writing dew points and verdicts into provided
+/// output slices.
+///
+/// Verdicts are the same as returned by [open_window_result](super::open_window_result):
+/// [Verdict::Open] if indoor dew point is higher than outdoor dew point,
+/// [Verdict::Close] otherwise.
+///
+/// # Panics
+///
+/// Panics if any of the series or output slices differ in length.
+pub fn open_window_batch(
+    indoor: &MeasurementSeries,
+    outdoor: &MeasurementSeries,
+    indoor_dew_points: &mut [f64],
+    outdoor_dew_points: &mut [f64],
+    verdicts: &mut [Verdict],
+) {
+    assert_eq!(
+        indoor.len(),
+        outdoor.len(),
+        "indoor and outdoor series must have the same length"
+    );
+    assert_eq!(
+        indoor.len(),
+        verdicts.len(),
+        "verdicts must have the same length as the series"
+    );
+
+    dew_points(indoor, indoor_dew_points);
+    dew_points(outdoor, outdoor_dew_points);
+
+    for ((verdict, indoor_dew_point), outdoor_dew_point) in verdicts
+        .iter_mut()
+        .zip(indoor_dew_points.iter())
+        .zip(outdoor_dew_points.iter())
+    {
+        *verdict = if indoor_dew_point > outdoor_dew_point {
+            Verdict::Open
+        } else {
+            Verdict::Close
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        dew_point::DewPointFormula, measurement::Measurement, open_window_result,
+        relative_humidity::RelativeHumidity, temperature::Temperature, verdict::Verdict,
+    };
+
+    use super::{dew_points, open_window_batch, MeasurementSeries};
+
+    const TEMPERATURES: [f64; 6] = [-20.0, -5.5, 0.0, 12.3, 21.0, 35.0];
+    const RELATIVE_HUMIDITIES: [f64; 6] = [1.0, 20.0, 45.5, 60.0, 85.0, 100.0];
+
+    #[test]
+    fn dew_points_match_scalar() {
+        let series = MeasurementSeries::new(&TEMPERATURES, &RELATIVE_HUMIDITIES).unwrap();
+        let mut output = [0.0; 6];
+
+        dew_points(&series, &mut output);
+
+        for (i, dew_point) in output.iter().enumerate() {
+            let expected = Measurement::new(
+                Temperature::new(TEMPERATURES[i]),
+                RelativeHumidity::from_percent(RELATIVE_HUMIDITIES[i]),
+            )
+            .calculate_dew_point();
+            assert!((expected - dew_point).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn dew_points_of_dry_air() {
+        let series = MeasurementSeries::new(&[20.0, 20.0], &[0.0, 50.0]).unwrap();
+        let mut output = [0.0; 2];
+
+        dew_points(&series, &mut output);
+
+        assert_eq!(DewPointFormula::AlduchovEskridge.dew_point(0.0), output[0]);
+        assert_eq!("9.26", format!("{:.2}", output[1]));
+    }
+
+    #[test]
+    fn verdicts_match_scalar() {
+        let mut outdoor_temperatures = TEMPERATURES;
+        outdoor_temperatures.reverse();
+        let indoor = MeasurementSeries::new(&TEMPERATURES, &RELATIVE_HUMIDITIES).unwrap();
+        let outdoor = MeasurementSeries::new(&outdoor_temperatures, &RELATIVE_HUMIDITIES).unwrap();
+        let mut indoor_dew_points = [0.0; 6];
+        let mut outdoor_dew_points = [0.0; 6];
+        let mut verdicts = [Verdict::Neutral; 6];
+
+        open_window_batch(
+            &indoor,
+            &outdoor,
+            &mut indoor_dew_points,
+            &mut outdoor_dew_points,
+            &mut verdicts,
+        );
+
+        for (i, verdict) in verdicts.iter().enumerate() {
+            let expected = open_window_result(
+                &Measurement::new(
+                    Temperature::new(TEMPERATURES[i]),
+                    RelativeHumidity::from_percent(RELATIVE_HUMIDITIES[i]),
+                ),
+                &Measurement::new(
+                    Temperature::new(outdoor_temperatures[i]),
+                    RelativeHumidity::from_percent(RELATIVE_HUMIDITIES[i]),
+                ),
+            );
+            assert_eq!(expected.verdict, *verdict);
+        }
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let error = MeasurementSeries::new(&TEMPERATURES, &RELATIVE_HUMIDITIES[..5]).unwrap_err();
+
+        assert_eq!(
+            "relative humidity must have 6 values, got 5",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "dew points must have the same length as the series")]
+    fn output_length_mismatch() {
+        let series = MeasurementSeries::new(&TEMPERATURES, &RELATIVE_HUMIDITIES).unwrap();
+
+        dew_points(&series, &mut [0.0; 5]);
+    }
+}
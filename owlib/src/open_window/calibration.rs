@@ -0,0 +1,191 @@
+use crate::Error;
+
+use super::{
+    limits::Limits, measurement::Measurement, relative_humidity::RelativeHumidity,
+    temperature::Temperature,
+};
+
+/// Holds corrections of a drifting sensor, obtained e.g. by comparing it with
+/// a reference sensor.
+///
+/// Corrected values are calculated as follows:
+/// - temperature: `raw + temperature_offset`,
+/// - relative humidity: `raw * relative_humidity_gain + relative_humidity_offset`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Calibration {
+    /// Temperature offset expressed in Celsius degrees (°C).
+    pub temperature_offset: f64,
+    /// Relative humidity offset expressed in percentage points (%).
+    pub relative_humidity_offset: f64,
+    /// Relative humidity gain.
+    pub relative_humidity_gain: f64,
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Self {
+            temperature_offset: 0.0,
+            relative_humidity_offset: 0.0,
+            relative_humidity_gain: 1.0,
+        }
+    }
+}
+
+impl Calibration {
+    /// Applies corrections to a raw `Measurement`.
+    ///
+    /// Returns an [Error] if corrected temperature or relative humidity is out
+    /// of range. Such a reading is not clamped, as it means either the sensor
+    /// or the calibration is off.
+    pub fn apply(&self, measurement: &Measurement) -> Result<Measurement, Error> {
+        self.apply_with_limits(measurement, &Limits::default())
+    }
+
+    /// Applies corrections to a raw `Measurement`, accepting corrected values
+    /// within provided limits instead of the default ones.
+    pub fn apply_with_limits(
+        &self,
+        measurement: &Measurement,
+        limits: &Limits,
+    ) -> Result<Measurement, Error> {
+        let temperature = Temperature::try_new_with_limits(
+            measurement.temperature.value() + self.temperature_offset,
+            limits,
+        )?;
+        let relative_humidity = RelativeHumidity::try_from_percent_with_limits(
+            measurement.relative_humidity.percent() * self.relative_humidity_gain
+                + self.relative_humidity_offset,
+            limits,
+        )?;
+
+        Ok(Measurement {
+            temperature,
+            relative_humidity,
+            ..measurement.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        limits::Limits, measurement::Measurement, relative_humidity::RelativeHumidity,
+        temperature::Temperature,
+    };
+
+    use super::Calibration;
+
+    macro_rules! calibration_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (calibration, (temperature, relative_humidity), expected) = $value;
+
+                    let measurement = Measurement::new(
+                        Temperature::new(temperature),
+                        RelativeHumidity::from_percent(relative_humidity),
+                    );
+
+                    let calibrated = calibration.apply(&measurement).ok().map(|measurement| {
+                        (
+                            format!("{:.2}", measurement.temperature.value()),
+                            format!("{:.2}", measurement.relative_humidity.percent()),
+                        )
+                    });
+
+                    let expected = expected.map(|(temperature, relative_humidity): (&str, &str)| {
+                        (temperature.to_string(), relative_humidity.to_string())
+                    });
+
+                    assert_eq!(expected, calibrated);
+                }
+             )*
+        }
+    }
+
+    calibration_tests! {
+        no_correction: (Calibration::default(), (18.0, 55.0), Some(("18.00", "55.00"))),
+        offsets: (
+            Calibration {
+                temperature_offset: -0.4,
+                relative_humidity_offset: 2.5,
+                ..Default::default()
+            },
+            (18.0, 55.0),
+            Some(("17.60", "57.50"))
+        ),
+        gain: (
+            Calibration {
+                relative_humidity_gain: 0.95,
+                relative_humidity_offset: 1.0,
+                ..Default::default()
+            },
+            (18.0, 60.0),
+            Some(("18.00", "58.00"))
+        ),
+        saturation: (
+            Calibration { relative_humidity_offset: 1.0, ..Default::default() },
+            (5.0, 99.0),
+            Some(("5.00", "100.00"))
+        ),
+        relative_humidity_above_maximum: (
+            Calibration { relative_humidity_offset: 3.0, ..Default::default() },
+            (5.0, 99.0),
+            None
+        ),
+        relative_humidity_below_minimum: (
+            Calibration { relative_humidity_offset: -3.0, ..Default::default() },
+            (30.0, 2.0),
+            None
+        ),
+        temperature_out_of_range: (
+            Calibration { temperature_offset: 1.0, ..Default::default() },
+            (100.0, 50.0),
+            None
+        ),
+    }
+
+    #[test]
+    fn relative_humidity_out_of_range_error() {
+        let calibration = Calibration {
+            relative_humidity_offset: 3.0,
+            ..Default::default()
+        };
+        let measurement = Measurement::new(Temperature::new(5.0), RelativeHumidity::new(99));
+
+        let error = calibration.apply(&measurement).unwrap_err();
+
+        assert_eq!(
+            "relative humidity must be at most 100, got 102",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn apply_with_limits() {
+        let limits = Limits::default().temperature(-150.0, 150.0);
+        let measurement = Measurement::new(
+            Temperature::try_new_with_limits(-150.0, &limits).unwrap(),
+            RelativeHumidity::new(50),
+        );
+
+        assert_eq!(
+            measurement,
+            Calibration::default()
+                .apply_with_limits(&measurement, &limits)
+                .unwrap()
+        );
+        assert!(Calibration::default().apply(&measurement).is_err());
+
+        let calibrated = Calibration {
+            temperature_offset: 0.5,
+            ..Default::default()
+        }
+        .apply_with_limits(&measurement, &limits)
+        .unwrap();
+
+        assert_eq!(-149.5, calibrated.temperature.value());
+    }
+}
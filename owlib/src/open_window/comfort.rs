@@ -0,0 +1,158 @@
+use core::fmt;
+
+use crate::math;
+use crate::psychrometrics::vapour_pressure;
+
+use super::measurement::Measurement;
+
+/// Thermal comfort classification based on the Canadian humidex scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComfortLevel {
+    /// Humidex below 30 - little to no discomfort.
+    Comfortable,
+    /// Humidex between 30 and 39 - some discomfort.
+    SomeDiscomfort,
+    /// Humidex between 40 and 45 - great discomfort, avoid exertion.
+    GreatDiscomfort,
+    /// Humidex above 45 - dangerous, heat stroke possible.
+    Dangerous,
+}
+
+impl ComfortLevel {
+    /// Classifies provided humidex value.
+    pub fn from_humidex(humidex: f64) -> Self {
+        if humidex < 30.0 {
+            Self::Comfortable
+        } else if humidex < 40.0 {
+            Self::SomeDiscomfort
+        } else if humidex <= 45.0 {
+            Self::GreatDiscomfort
+        } else {
+            Self::Dangerous
+        }
+    }
+
+    /// Returns a human-readable message describing the comfort level.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::Comfortable => "Comfortable.",
+            Self::SomeDiscomfort => "Some discomfort.",
+            Self::GreatDiscomfort => "Great discomfort, avoid exertion.",
+            Self::Dangerous => "Dangerous, heat stroke possible.",
+        }
+    }
+}
+
+impl fmt::Display for ComfortLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+/// Calculates heat index (°C) using NOAA algorithm - Rothfusz regression with
+/// its low and high humidity adjustments, falling back to Steadman's simple
+/// formula for mild conditions.
+pub fn heat_index(measurement: &Measurement) -> f64 {
+    let t = measurement.temperature.as_fahrenheit();
+    let rh = measurement.relative_humidity.percent();
+
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+
+    let heat_index = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        let regression = -42.379 + 2.04901523 * t + 10.14333127 * rh
+            - 0.22475541 * t * rh
+            - 0.00683783 * t * t
+            - 0.05481717 * rh * rh
+            + 0.00122874 * t * t * rh
+            + 0.00085282 * t * rh * rh
+            - 0.00000199 * t * t * rh * rh;
+
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            regression - (13.0 - rh) / 4.0 * math::sqrt((17.0 - math::abs(t - 95.0)) / 17.0)
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            regression + (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0)
+        } else {
+            regression
+        }
+    };
+
+    (heat_index - 32.0) * 5.0 / 9.0
+}
+
+/// Calculates Canadian humidex (°C), based on temperature and dew point.
+pub fn humidex(measurement: &Measurement) -> f64 {
+    let dew_point_k = measurement.calculate_dew_point() + 273.15;
+    let vapour_pressure = 6.11 * math::exp(5417.7530 * (1.0 / 273.16 - 1.0 / dew_point_k));
+
+    measurement.temperature.value() + 0.5555 * (vapour_pressure - 10.0)
+}
+
+/// Calculates Steadman's apparent temperature (°C) for shade, as used by the
+/// Australian Bureau of Meteorology. `wind_speed` is expressed in m/s - use
+/// `0.0` indoors.
+pub fn apparent_temperature(measurement: &Measurement, wind_speed: f64) -> f64 {
+    measurement.temperature.value() + 0.33 * vapour_pressure(measurement) - 0.70 * wind_speed - 4.00
+}
+
+/// Classifies thermal comfort of the measured air, based on its humidex.
+pub fn comfort_level(measurement: &Measurement) -> ComfortLevel {
+    ComfortLevel::from_humidex(humidex(measurement))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, relative_humidity::RelativeHumidity, temperature::Temperature,
+    };
+
+    use super::ComfortLevel;
+
+    macro_rules! comfort_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (temperature, relative_humidity, expected) = $value;
+
+                    let measurement = Measurement::new(
+                        Temperature::new(temperature),
+                        RelativeHumidity::new(relative_humidity),
+                    );
+
+                    assert_eq!(
+                        expected,
+                        [
+                            format!("{:.2}", super::heat_index(&measurement)),
+                            format!("{:.2}", super::humidex(&measurement)),
+                            format!("{:.2}", super::apparent_temperature(&measurement, 0.0)),
+                            format!("{:.2}", super::apparent_temperature(&measurement, 3.0)),
+                        ]
+                    );
+                }
+             )*
+        }
+    }
+
+    comfort_tests! {
+        mild: (18.0, 55, ["17.29", "18.75", "17.74", "15.64"]),
+        hot_humid: (30.0, 70, ["35.04", "41.21", "35.79", "33.69"]),
+        very_humid: (27.0, 90, ["31.09", "39.58", "33.57", "31.47"]),
+        very_dry: (38.0, 10, ["34.73", "36.12", "36.19", "34.09"]),
+    }
+
+    #[test]
+    fn comfort_level() {
+        assert_eq!(ComfortLevel::Comfortable, ComfortLevel::from_humidex(18.75));
+        assert_eq!(
+            ComfortLevel::SomeDiscomfort,
+            ComfortLevel::from_humidex(39.58)
+        );
+        assert_eq!(
+            ComfortLevel::GreatDiscomfort,
+            ComfortLevel::from_humidex(41.21)
+        );
+        assert_eq!(ComfortLevel::Dangerous, ComfortLevel::from_humidex(46.0));
+    }
+}
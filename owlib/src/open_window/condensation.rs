@@ -0,0 +1,202 @@
+use crate::{
+    psychrometrics::{saturation_vapour_pressure_at, vapour_pressure},
+    Error, Field,
+};
+
+use super::{measurement::Measurement, temperature::Temperature};
+
+/// Internal surface thermal resistance (m²·K/W) used to derive a temperature
+/// factor from a U-value, as recommended by EN ISO 13788 for mould assessment.
+pub const INTERNAL_SURFACE_RESISTANCE: f64 = 0.25;
+
+/// Surface relative humidity (%) at and above which mould is likely to grow.
+pub const MOULD_RELATIVE_HUMIDITY: f64 = 80.0;
+
+/// Describes thermal properties of a wall, window frame or other building element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Surface {
+    /// Thermal transmittance (U-value) of the element expressed in W/(m²·K),
+    /// a non-negative value.
+    UValue(f64),
+    /// Temperature factor at the internal surface (fRsi), a value between
+    /// `0.0` (surface at outdoor temperature) and `1.0` (surface at indoor
+    /// temperature).
+    TemperatureFactor(f64),
+}
+
+impl Surface {
+    /// Returns temperature factor at the internal surface (fRsi), within
+    /// `0.0..=1.0`.
+    ///
+    /// The factor derived from a U-value is `1 - U·Rsi`, which is clamped to
+    /// `0.0` for U-values above `1 / Rsi` (4 W/(m²·K), e.g. single glazing),
+    /// as a surface is never colder than outdoor air.
+    ///
+    /// Returns an [Error] if the U-value is negative, or the temperature factor
+    /// does not fall within `0.0..=1.0`.
+    pub fn temperature_factor(&self) -> Result<f64, Error> {
+        match self {
+            Self::UValue(u_value) => {
+                let u_value = Error::validate_min(Field::UValue, *u_value, 0.0)?;
+
+                Ok((1.0 - u_value * INTERNAL_SURFACE_RESISTANCE).max(0.0))
+            }
+            Self::TemperatureFactor(temperature_factor) => {
+                Error::validate(Field::TemperatureFactor, *temperature_factor, 0.0, 1.0)
+            }
+        }
+    }
+}
+
+/// Holds condensation and mould risk assessment of an internal surface.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SurfaceRisk {
+    /// Internal surface temperature (°C).
+    pub surface_temperature: f64,
+    /// Relative humidity (%) of indoor air touching the surface, capped at 100%.
+    pub surface_relative_humidity: f64,
+    /// Indoor air condenses on the surface.
+    pub condensation: bool,
+    /// Surface relative humidity reaches [MOULD_RELATIVE_HUMIDITY].
+    pub mould_risk: bool,
+}
+
+/// Estimates internal surface temperature of a building element and assesses
+/// whether indoor air condenses on it and whether mould is likely to grow.
+///
+/// Returns an [Error] if the surface is described by an invalid U-value or
+/// temperature factor, see [Surface::temperature_factor].
+///
+/// # Example
+///
+/// ```
+/// use owlib::open_window::condensation::{assess_surface, Surface};
+/// use owlib::open_window::measurement::Measurement;
+/// use owlib::open_window::relative_humidity::RelativeHumidity;
+/// use owlib::open_window::temperature::Temperature;
+///
+/// let indoor_measurement = Measurement::new(Temperature::new(20.0), RelativeHumidity::new(60));
+///
+/// let risk = assess_surface(
+///     &indoor_measurement,
+///     &Temperature::new(0.0),
+///     &Surface::TemperatureFactor(0.7),
+/// )
+/// .unwrap();
+///
+/// assert!(risk.mould_risk);
+/// assert!(!risk.condensation);
+/// ```
+pub fn assess_surface(
+    indoor_measurement: &Measurement,
+    outdoor_temperature: &Temperature,
+    surface: &Surface,
+) -> Result<SurfaceRisk, Error> {
+    let indoor_temperature = indoor_measurement.temperature.value();
+    let outdoor_temperature = outdoor_temperature.value();
+
+    let surface_temperature = outdoor_temperature
+        + surface.temperature_factor()? * (indoor_temperature - outdoor_temperature);
+
+    let surface_relative_humidity = vapour_pressure(indoor_measurement)
+        / saturation_vapour_pressure_at(surface_temperature)
+        * 100.0;
+
+    Ok(SurfaceRisk {
+        surface_temperature,
+        surface_relative_humidity: surface_relative_humidity.min(100.0),
+        condensation: surface_relative_humidity >= 100.0,
+        mould_risk: surface_relative_humidity >= MOULD_RELATIVE_HUMIDITY,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, relative_humidity::RelativeHumidity, temperature::Temperature,
+    };
+
+    use super::{assess_surface, Surface};
+
+    macro_rules! assess_surface_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (surface, expected) = $value;
+
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(20.0), RelativeHumidity::new(60));
+
+                    let risk = assess_surface(&indoor_measurement, &Temperature::new(0.0), &surface)
+                        .unwrap();
+
+                    assert_eq!(
+                        expected,
+                        (
+                            format!("{:.2}", risk.surface_temperature),
+                            format!("{:.2}", risk.surface_relative_humidity),
+                            risk.condensation,
+                            risk.mould_risk,
+                        )
+                    );
+                }
+             )*
+        }
+    }
+
+    assess_surface_tests! {
+        temperature_factor: (
+            Surface::TemperatureFactor(0.7),
+            ("14.00".to_string(), "87.75".to_string(), false, true)
+        ),
+        insulated_wall: (
+            Surface::UValue(1.1),
+            ("14.50".to_string(), "84.96".to_string(), false, true)
+        ),
+        old_window: (
+            Surface::UValue(2.8),
+            ("6.00".to_string(), "100.00".to_string(), true, true)
+        ),
+        warm_surface: (
+            Surface::TemperatureFactor(0.95),
+            ("19.00".to_string(), "63.85".to_string(), false, false)
+        ),
+        single_glazing: (
+            Surface::UValue(5.0),
+            ("0.00".to_string(), "100.00".to_string(), true, true)
+        ),
+    }
+
+    macro_rules! invalid_surface_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (surface, expected) = $value;
+
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(20.0), RelativeHumidity::new(60));
+
+                    let error =
+                        assess_surface(&indoor_measurement, &Temperature::new(0.0), &surface)
+                            .unwrap_err();
+
+                    assert_eq!(expected, error.to_string());
+                }
+             )*
+        }
+    }
+
+    invalid_surface_tests! {
+        negative_u_value: (Surface::UValue(-1.0), "U-value must be at least 0, got -1"),
+        temperature_factor_above_one: (
+            Surface::TemperatureFactor(1.5),
+            "temperature factor must be at most 1, got 1.5"
+        ),
+        negative_temperature_factor: (
+            Surface::TemperatureFactor(-0.2),
+            "temperature factor must be at least 0, got -0.2"
+        ),
+    }
+}
@@ -0,0 +1,172 @@
+use crate::math;
+
+/// Coefficients of a Magnus type approximation `a * exp(b * T / (c + T))` of
+/// saturation vapour pressure (hPa).
+struct Magnus {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl Magnus {
+    fn saturation_vapour_pressure(&self, temperature: f64) -> f64 {
+        self.a * math::exp((self.b * temperature) / (self.c + temperature))
+    }
+
+    fn dew_point(&self, vapour_pressure: f64) -> f64 {
+        if vapour_pressure <= 0.0 {
+            // Limit of the formula for vapour pressure approaching zero.
+            return -self.c;
+        }
+
+        let gamma = math::ln(vapour_pressure / self.a);
+        self.c * gamma / (self.b - gamma)
+    }
+
+    /// Equivalent of `dew_point(relative_humidity / 100 * saturation_vapour_pressure(temperature))`
+    /// for every pair of values, which avoids the `exp` and `ln` round trip.
+    ///
+    /// Logarithms are calculated in a separate pass, so that the second pass has
+    /// neither calls nor branches - dry air is handled with a select - and can be
+    /// vectorised by the compiler.
+    fn dew_points_from_relative_humidity(
+        &self,
+        temperatures: &[f64],
+        relative_humidities: &[f64],
+        dew_points: &mut [f64],
+    ) {
+        for (dew_point, &relative_humidity) in dew_points.iter_mut().zip(relative_humidities) {
+            *dew_point = math::ln(relative_humidity / 100.0);
+        }
+
+        for ((dew_point, &temperature), &relative_humidity) in dew_points
+            .iter_mut()
+            .zip(temperatures)
+            .zip(relative_humidities)
+        {
+            let gamma = *dew_point + (self.b * temperature) / (self.c + temperature);
+            let value = self.c * gamma / (self.b - gamma);
+            *dew_point = if relative_humidity <= 0.0 {
+                -self.c
+            } else {
+                value
+            };
+        }
+    }
+}
+
+/// Calculates dew points (°C) from temperatures (°C) and relative humidities (%)
+/// using the default [DewPointFormula::AlduchovEskridge] formula and writes
+/// them into `dew_points`. All slices must have the same length.
+pub(crate) fn default_dew_points(
+    temperatures: &[f64],
+    relative_humidities: &[f64],
+    dew_points: &mut [f64],
+) {
+    ALDUCHOV_ESKRIDGE.dew_points_from_relative_humidity(
+        temperatures,
+        relative_humidities,
+        dew_points,
+    );
+}
+
+const MAGNUS_TETENS: Magnus = Magnus {
+    a: 6.1078,
+    b: 17.27,
+    c: 237.7,
+};
+
+const ALDUCHOV_ESKRIDGE: Magnus = Magnus {
+    a: 6.1094,
+    b: 17.625,
+    c: 243.04,
+};
+
+const SONNTAG_WATER: Magnus = Magnus {
+    a: 6.112,
+    b: 17.62,
+    c: 243.12,
+};
+
+const WMO_ICE: Magnus = Magnus {
+    a: 6.112,
+    b: 22.46,
+    c: 272.62,
+};
+
+/// Coefficients of Arden Buck (1996) equation over water:
+/// `a * exp((b - T / d) * (T / (c + T)))`.
+const BUCK_A: f64 = 6.1121;
+const BUCK_B: f64 = 18.678;
+const BUCK_C: f64 = 257.14;
+const BUCK_D: f64 = 234.5;
+
+/// Formula used to approximate saturation vapour pressure and, as a consequence,
+/// the dew point.
+///
+/// Formulas differ in accuracy depending on the temperature range. Magnus type
+/// formulas with Alduchov-Eskridge coefficients are accurate between -40 °C and
+/// 50 °C, while Arden Buck equation stays accurate further above 50 °C. Below 0 °C
+/// water vapour deposits as frost, which is modelled by [DewPointFormula::WmoOverIce].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DewPointFormula {
+    /// Magnus-Tetens formula (`b = 17.27`, `c = 237.7 °C`).
+    MagnusTetens,
+    /// Magnus formula with Alduchov and Eskridge (1996) coefficients
+    /// (`b = 17.625`, `c = 243.04 °C`).
+    #[default]
+    AlduchovEskridge,
+    /// Arden Buck (1996) equation over water.
+    ArdenBuck,
+    /// Magnus formula with Sonntag (1990) coefficients over water
+    /// (`b = 17.62`, `c = 243.12 °C`).
+    Sonntag,
+    /// Magnus formula with WMO (2008) coefficients over ice (`b = 22.46`,
+    /// `c = 272.62 °C`). Yields a frost point instead of a dew point.
+    WmoOverIce,
+}
+
+impl DewPointFormula {
+    /// Calculates saturation vapour pressure over water (hPa) at provided temperature (°C).
+    ///
+    /// Relative humidity is by convention defined with respect to water, even
+    /// below 0 °C, therefore [DewPointFormula::WmoOverIce] uses Sonntag (1990)
+    /// coefficients over water here.
+    pub fn saturation_vapour_pressure(&self, temperature: f64) -> f64 {
+        match self {
+            Self::MagnusTetens => MAGNUS_TETENS.saturation_vapour_pressure(temperature),
+            Self::AlduchovEskridge => ALDUCHOV_ESKRIDGE.saturation_vapour_pressure(temperature),
+            Self::ArdenBuck => {
+                BUCK_A
+                    * math::exp(
+                        (BUCK_B - temperature / BUCK_D) * (temperature / (BUCK_C + temperature)),
+                    )
+            }
+            Self::Sonntag | Self::WmoOverIce => {
+                SONNTAG_WATER.saturation_vapour_pressure(temperature)
+            }
+        }
+    }
+
+    /// Calculates a temperature (°C) at which provided vapour pressure (hPa)
+    /// saturates the air - a dew point, or a frost point for
+    /// [DewPointFormula::WmoOverIce].
+    ///
+    /// Vapour pressure of zero (completely dry air) yields the lowest value
+    /// the formula converges to, rather than -∞.
+    pub fn dew_point(&self, vapour_pressure: f64) -> f64 {
+        match self {
+            Self::MagnusTetens => MAGNUS_TETENS.dew_point(vapour_pressure),
+            Self::AlduchovEskridge => ALDUCHOV_ESKRIDGE.dew_point(vapour_pressure),
+            Self::ArdenBuck if vapour_pressure <= 0.0 => -BUCK_C,
+            Self::ArdenBuck => {
+                // Solves `T² / d - (b - y) * T + y * c = 0` for `T`, where `y = ln(e / a)`.
+                let y = math::ln(vapour_pressure / BUCK_A);
+                let p = BUCK_B - y;
+                BUCK_D / 2.0 * (p - math::sqrt(p * p - 4.0 * y * BUCK_C / BUCK_D))
+            }
+            Self::Sonntag => SONNTAG_WATER.dew_point(vapour_pressure),
+            Self::WmoOverIce => WMO_ICE.dew_point(vapour_pressure),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::vec::Vec;
+
+use super::{measurement::Measurement, policy::DecisionPolicy};
+
+/// Holds an outdoor `Measurement` (e.g. from an hourly weather forecast)
+/// together with its point in time.
+///
+/// Time can be of any type, e.g. an hour of the day or a full timestamp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForecastEntry<T> {
+    /// Point in time of the measurement.
+    pub time: T,
+    /// Outdoor measurement.
+    pub measurement: Measurement,
+}
+
+/// Describes a continuous period of time in which airing decreases indoor humidity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VentilationWindow<T> {
+    /// Time of the first forecast entry in the window.
+    pub start: T,
+    /// Time of the first forecast entry following the window (exclusive), or
+    /// `None` if the window lasts until the end of the forecast.
+    pub end: Option<T>,
+    /// Mean difference (°C) between indoor and outdoor dew points within the window.
+    pub dew_point_gap: f64,
+    /// Highest difference (°C) between indoor and outdoor dew points within the window.
+    pub peak_dew_point_gap: f64,
+}
+
+/// Finds time windows in which airing is most effective, given current indoor
+/// measurement and an outdoor forecast ordered by time.
+///
+/// A window is a run of consecutive forecast entries for which `policy`
+/// returns [Verdict::Open](super::verdict::Verdict::Open). Windows are ranked
+/// by their mean dew point gap - the most effective window comes first.
+///
+/// # Example
+///
+/// ```
+/// use owlib::open_window::forecast::{best_ventilation_windows, ForecastEntry};
+/// use owlib::open_window::measurement::Measurement;
+/// use owlib::open_window::policy::DecisionPolicy;
+/// use owlib::open_window::relative_humidity::RelativeHumidity;
+/// use owlib::open_window::temperature::Temperature;
+///
+/// let measurement = |temperature, relative_humidity| {
+///     Measurement::new(Temperature::new(temperature), RelativeHumidity::new(relative_humidity))
+/// };
+///
+/// let indoor_measurement = measurement(20.0, 60);
+/// let forecast: Vec<_> = [(5, 14.0, 95), (6, 10.0, 80), (7, 12.0, 80), (8, 18.0, 90)]
+///     .into_iter()
+///     .map(|(hour, temperature, relative_humidity)| ForecastEntry {
+///         time: hour,
+///         measurement: measurement(temperature, relative_humidity),
+///     })
+///     .collect();
+///
+/// let windows = best_ventilation_windows(&DecisionPolicy::default(), &indoor_measurement, &forecast);
+///
+/// assert_eq!((6, Some(8)), (windows[0].start, windows[0].end));
+/// ```
+pub fn best_ventilation_windows<T: Clone>(
+    policy: &DecisionPolicy,
+    indoor_measurement: &Measurement,
+    forecast: &[ForecastEntry<T>],
+) -> Vec<VentilationWindow<T>> {
+    let mut windows = Vec::new();
+    let mut current: Option<(usize, f64, f64)> = None;
+    let mut previous_open_window = None;
+
+    for (index, entry) in forecast.iter().enumerate() {
+        let result = policy.evaluate(indoor_measurement, &entry.measurement, previous_open_window);
+        previous_open_window = Some(result.open_window);
+
+        if result.open_window {
+            let gap = result.indoor_dew_point - result.outdoor_dew_point;
+            current = match current {
+                Some((start, sum, peak)) => Some((start, sum + gap, peak.max(gap))),
+                None => Some((index, gap, gap)),
+            };
+        } else if let Some((start, sum, peak)) = current.take() {
+            windows.push(window(forecast, start, index, sum, peak));
+        }
+    }
+
+    if let Some((start, sum, peak)) = current {
+        windows.push(window(forecast, start, forecast.len(), sum, peak));
+    }
+
+    windows.sort_by(|a, b| b.dew_point_gap.total_cmp(&a.dew_point_gap));
+
+    windows
+}
+
+/// Creates a window spanning forecast entries in `start..end` range, `end`
+/// being equal to forecast length if the window lasts until its end.
+fn window<T: Clone>(
+    forecast: &[ForecastEntry<T>],
+    start: usize,
+    end: usize,
+    gap_sum: f64,
+    peak_gap: f64,
+) -> VentilationWindow<T> {
+    VentilationWindow {
+        start: forecast[start].time.clone(),
+        end: forecast.get(end).map(|entry| entry.time.clone()),
+        dew_point_gap: gap_sum / (end - start) as f64,
+        peak_dew_point_gap: peak_gap,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, policy::DecisionPolicy, relative_humidity::RelativeHumidity,
+        temperature::Temperature,
+    };
+
+    use super::{best_ventilation_windows, ForecastEntry};
+
+    macro_rules! best_ventilation_windows_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (entries, expected) = $value;
+                    let forecast: Vec<_> = entries
+                        .iter()
+                        .map(|&(hour, temperature, relative_humidity): &(u8, f64, u8)| {
+                            ForecastEntry {
+                                time: hour,
+                                measurement: Measurement::new(
+                                    Temperature::new(temperature),
+                                    RelativeHumidity::new(relative_humidity),
+                                ),
+                            }
+                        })
+                        .collect();
+
+                    // Indoor dew point: 12.00 °C.
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(20.0), RelativeHumidity::new(60));
+
+                    let windows = best_ventilation_windows(
+                        &DecisionPolicy::default(),
+                        &indoor_measurement,
+                        &forecast,
+                    );
+
+                    let windows: Vec<_> = windows
+                        .iter()
+                        .map(|window| {
+                            (window.start, window.end, format!("{:.2}", window.dew_point_gap))
+                        })
+                        .collect();
+                    let expected: Vec<_> = expected
+                        .iter()
+                        .map(|&(start, end, gap): &(u8, Option<u8>, &str)| {
+                            (start, end, gap.to_string())
+                        })
+                        .collect();
+
+                    assert_eq!(expected, windows);
+                }
+             )*
+        }
+    }
+
+    best_ventilation_windows_tests! {
+        empty_forecast: (&[], &[]),
+        no_window: (&[(0, 18.0, 90), (1, 17.0, 95)], &[]),
+        single_window: (
+            &[(5, 14.0, 95), (6, 10.0, 80), (7, 12.0, 80), (8, 18.0, 90)],
+            &[(6, Some(8), "4.32")]
+        ),
+        ranked_windows: (
+            &[(6, 10.0, 90), (7, 18.0, 90), (8, 4.0, 80), (9, 2.0, 80), (10, 18.0, 90)],
+            &[(8, Some(10), "12.11"), (6, Some(7), "3.57")]
+        ),
+        window_until_end: (
+            &[(22, 18.0, 90), (23, 8.0, 80)],
+            &[(23, None, "7.24")]
+        ),
+    }
+}
@@ -0,0 +1,223 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{
+    measurement::Measurement,
+    strategy::{StrategyContext, VentilationStrategy},
+    verdict::Verdict,
+    OpenWindowResult,
+};
+
+/// Holds the "open window" result of a single room.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoomResult {
+    /// Name of the room.
+    pub name: String,
+    /// Result calculated for the room.
+    pub result: OpenWindowResult,
+}
+
+/// Holds "open window" results of all rooms sharing the same outdoor measurement.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HouseResult {
+    /// Results of individual rooms, in the order rooms were provided.
+    pub rooms: Vec<RoomResult>,
+    /// Human-readable recommendation summarising all rooms, e.g. "Air the
+    /// bathroom and kitchen, keep the bedroom closed."
+    pub recommendation: String,
+}
+
+impl HouseResult {
+    /// Returns names of rooms for which the verdict is equal to provided one.
+    pub fn rooms_with_verdict(&self, verdict: Verdict) -> Vec<&str> {
+        self.rooms
+            .iter()
+            .filter(|room| room.result.verdict == verdict)
+            .map(|room| room.name.as_str())
+            .collect()
+    }
+}
+
+/// Answers "open window" question for every room of a house, given named
+/// indoor measurements and a single outdoor measurement.
+///
+/// # Example
+///
+/// ```
+/// use owlib::open_window::house::evaluate_house;
+/// use owlib::open_window::measurement::Measurement;
+/// use owlib::open_window::policy::DecisionPolicy;
+/// use owlib::open_window::relative_humidity::RelativeHumidity;
+/// use owlib::open_window::temperature::Temperature;
+///
+/// let measurement = |temperature, relative_humidity| {
+///     Measurement::new(Temperature::new(temperature), RelativeHumidity::new(relative_humidity))
+/// };
+///
+/// let rooms = [
+///     ("bathroom", measurement(24.0, 80)),
+///     ("kitchen", measurement(22.0, 65)),
+///     ("bedroom", measurement(17.0, 45)),
+/// ];
+///
+/// let house = evaluate_house(&DecisionPolicy::default(), &measurement(12.0, 80), &rooms);
+///
+/// assert_eq!(
+///     "Air the bathroom and kitchen, keep the bedroom closed.",
+///     house.recommendation
+/// );
+/// ```
+pub fn evaluate_house<S: VentilationStrategy + ?Sized>(
+    strategy: &S,
+    outdoor_measurement: &Measurement,
+    indoor_measurements: &[(&str, Measurement)],
+) -> HouseResult {
+    let context = StrategyContext::default();
+
+    let rooms = indoor_measurements
+        .iter()
+        .map(|(name, indoor_measurement)| RoomResult {
+            name: name.to_string(),
+            result: strategy.evaluate(indoor_measurement, outdoor_measurement, &context),
+        })
+        .collect();
+
+    let mut house = HouseResult {
+        rooms,
+        recommendation: String::new(),
+    };
+    house.recommendation = recommendation(&house);
+
+    house
+}
+
+fn recommendation(house: &HouseResult) -> String {
+    let parts: Vec<String> = [
+        (Verdict::Open, "air the ", ""),
+        (Verdict::Close, "keep the ", " closed"),
+        (Verdict::Neutral, "no need to air the ", ""),
+    ]
+    .into_iter()
+    .filter_map(|(verdict, prefix, suffix)| {
+        let names = house.rooms_with_verdict(verdict);
+        (!names.is_empty()).then(|| format!("{prefix}{}{suffix}", enumerate(&names)))
+    })
+    .collect();
+
+    if parts.is_empty() {
+        return String::from("There are no rooms to evaluate.");
+    }
+
+    let sentence = parts.join(", ");
+    let mut chars = sentence.chars();
+    let first = chars
+        .next()
+        .map(|c| c.to_ascii_uppercase())
+        .unwrap_or_default();
+
+    format!("{first}{}.", chars.as_str())
+}
+
+/// Joins names as in "a, b and c".
+fn enumerate(names: &[&str]) -> String {
+    match names.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, policy::DecisionPolicy, relative_humidity::RelativeHumidity,
+        temperature::Temperature, verdict::Verdict,
+    };
+
+    use super::evaluate_house;
+
+    macro_rules! recommendation_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (policy, rooms, expected): (DecisionPolicy, &[(&str, f64, u8)], &str) =
+                        $value;
+
+                    let rooms: Vec<(&str, Measurement)> = rooms
+                        .iter()
+                        .map(|&(name, temperature, relative_humidity)| {
+                            (
+                                name,
+                                Measurement::new(
+                                    Temperature::new(temperature),
+                                    RelativeHumidity::new(relative_humidity),
+                                ),
+                            )
+                        })
+                        .collect();
+
+                    // Outdoor dew point: 8.67 °C.
+                    let outdoor_measurement =
+                        Measurement::new(Temperature::new(12.0), RelativeHumidity::new(80));
+
+                    let house = evaluate_house(&policy, &outdoor_measurement, &rooms);
+
+                    assert_eq!(rooms.len(), house.rooms.len());
+                    assert_eq!(expected, house.recommendation);
+                }
+             )*
+        }
+    }
+
+    recommendation_tests! {
+        no_rooms: (DecisionPolicy::default(), &[], "There are no rooms to evaluate."),
+        single_room: (DecisionPolicy::default(), &[("bathroom", 24.0, 80)], "Air the bathroom."),
+        all_closed: (
+            DecisionPolicy::default(),
+            &[("bedroom", 17.0, 45), ("office", 19.0, 40)],
+            "Keep the bedroom and office closed."
+        ),
+        mixed: (
+            DecisionPolicy::default().min_dew_point_delta(1.0),
+            &[
+                ("bathroom", 24.0, 80),
+                ("kitchen", 22.0, 65),
+                ("living room", 21.0, 50),
+                ("bedroom", 17.0, 45),
+                ("hallway", 17.0, 60),
+            ],
+            "Air the bathroom, kitchen and living room, keep the bedroom closed, \
+             no need to air the hallway."
+        ),
+    }
+
+    #[test]
+    fn rooms_with_verdict() {
+        let rooms = [
+            (
+                "bathroom",
+                Measurement::new(Temperature::new(24.0), RelativeHumidity::new(80)),
+            ),
+            (
+                "bedroom",
+                Measurement::new(Temperature::new(17.0), RelativeHumidity::new(45)),
+            ),
+        ];
+
+        let house = evaluate_house(
+            &DecisionPolicy::default(),
+            &Measurement::new(Temperature::new(12.0), RelativeHumidity::new(80)),
+            &rooms,
+        );
+
+        assert_eq!(vec!["bathroom"], house.rooms_with_verdict(Verdict::Open));
+        assert_eq!(vec!["bedroom"], house.rooms_with_verdict(Verdict::Close));
+        assert!(house.rooms_with_verdict(Verdict::Neutral).is_empty());
+    }
+}
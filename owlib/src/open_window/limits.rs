@@ -0,0 +1,347 @@
+use crate::{Error, Field};
+
+use super::{
+    relative_humidity::{MAX_HUMIDITY, MIN_HUMIDITY},
+    temperature::{LOWEST_TEMP_LIMIT, MAX_TEMP, MIN_TEMP},
+};
+
+/// Holds ranges of values accepted by [Temperature](super::temperature::Temperature)
+/// and [RelativeHumidity](super::relative_humidity::RelativeHumidity).
+///
+/// Default limits are [MIN_TEMP]..=[MAX_TEMP] and [MIN_HUMIDITY]..=[MAX_HUMIDITY].
+/// Temperature limits can not be widened below [LOWEST_TEMP_LIMIT].
+///
+/// # Example
+///
+/// ```
+/// use owlib::open_window::limits::Limits;
+/// use owlib::open_window::relative_humidity::RelativeHumidity;
+/// use owlib::open_window::temperature::Temperature;
+///
+/// // A cold store, which additionally accepts completely dry air.
+/// let limits = Limits::default()
+///     .temperature(-40.0, 30.0)
+///     .relative_humidity(0.0, 100.0);
+///
+/// assert!(Temperature::try_new_with_limits(35.0, &limits).is_err());
+/// assert!(RelativeHumidity::try_from_percent_with_limits(0.0, &limits).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "LimitsData", into = "LimitsData")
+)]
+pub struct Limits {
+    min_temperature: f64,
+    max_temperature: f64,
+    min_relative_humidity: f64,
+    max_relative_humidity: f64,
+}
+
+/// Serialized form of [Limits], validated by the builder methods when deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct LimitsData {
+    min_temperature: f64,
+    max_temperature: f64,
+    min_relative_humidity: f64,
+    max_relative_humidity: f64,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            min_temperature: MIN_TEMP,
+            max_temperature: MAX_TEMP,
+            min_relative_humidity: MIN_HUMIDITY as f64,
+            max_relative_humidity: MAX_HUMIDITY as f64,
+        }
+    }
+}
+
+impl Limits {
+    /// Sets accepted temperature range (°C).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the values is not finite, `min` is below
+    /// [LOWEST_TEMP_LIMIT] or `min` is greater than `max`.
+    pub fn temperature(self, min: f64, max: f64) -> Self {
+        match self.try_temperature(min, max) {
+            Ok(limits) => limits,
+            Err(error) => panic!("invalid temperature limits: {error}"),
+        }
+    }
+
+    /// Sets accepted temperature range (°C).
+    ///
+    /// As opposed to [Limits::temperature] function it does not panic, but
+    /// returns an [Error] instead.
+    pub fn try_temperature(mut self, min: f64, max: f64) -> Result<Self, Error> {
+        let min = Error::validate_min(Field::Temperature, min, LOWEST_TEMP_LIMIT)?;
+        let max = Error::validate_min(Field::Temperature, max, min)?;
+
+        self.min_temperature = min;
+        self.max_temperature = max;
+        Ok(self)
+    }
+
+    /// Sets accepted relative humidity range (%).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range does not fall within `0.0..=100.0` or `min` is
+    /// greater than `max`.
+    pub fn relative_humidity(self, min: f64, max: f64) -> Self {
+        match self.try_relative_humidity(min, max) {
+            Ok(limits) => limits,
+            Err(error) => panic!("invalid relative humidity limits: {error}"),
+        }
+    }
+
+    /// Sets accepted relative humidity range (%).
+    ///
+    /// As opposed to [Limits::relative_humidity] function it does not panic,
+    /// but returns an [Error] instead.
+    pub fn try_relative_humidity(mut self, min: f64, max: f64) -> Result<Self, Error> {
+        let min = Error::validate(Field::RelativeHumidity, min, 0.0, MAX_HUMIDITY as f64)?;
+        let max = Error::validate(Field::RelativeHumidity, max, min, MAX_HUMIDITY as f64)?;
+
+        self.min_relative_humidity = min;
+        self.max_relative_humidity = max;
+        Ok(self)
+    }
+
+    /// Returns minimum accepted temperature (°C).
+    pub fn min_temperature(&self) -> f64 {
+        self.min_temperature
+    }
+
+    /// Returns maximum accepted temperature (°C).
+    pub fn max_temperature(&self) -> f64 {
+        self.max_temperature
+    }
+
+    /// Returns minimum accepted relative humidity (%).
+    pub fn min_relative_humidity(&self) -> f64 {
+        self.min_relative_humidity
+    }
+
+    /// Returns maximum accepted relative humidity (%).
+    pub fn max_relative_humidity(&self) -> f64 {
+        self.max_relative_humidity
+    }
+
+    pub(crate) fn validate_temperature(&self, value: f64) -> Result<f64, Error> {
+        Error::validate(
+            Field::Temperature,
+            value,
+            self.min_temperature,
+            self.max_temperature,
+        )
+    }
+
+    pub(crate) fn validate_relative_humidity(&self, value: f64) -> Result<f64, Error> {
+        Error::validate(
+            Field::RelativeHumidity,
+            value,
+            self.min_relative_humidity,
+            self.max_relative_humidity,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<LimitsData> for Limits {
+    type Error = Error;
+
+    fn try_from(data: LimitsData) -> Result<Self, Self::Error> {
+        Limits::default()
+            .try_temperature(data.min_temperature, data.max_temperature)?
+            .try_relative_humidity(data.min_relative_humidity, data.max_relative_humidity)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Limits> for LimitsData {
+    fn from(limits: Limits) -> Self {
+        Self {
+            min_temperature: limits.min_temperature,
+            max_temperature: limits.max_temperature,
+            min_relative_humidity: limits.min_relative_humidity,
+            max_relative_humidity: limits.max_relative_humidity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, open_window_result, relative_humidity::RelativeHumidity,
+        temperature::Temperature, uncertainty::SensorTolerance,
+    };
+
+    use super::Limits;
+
+    macro_rules! limits_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (limits, (temperature, relative_humidity), expected): (Limits, _, _) =
+                        $value;
+
+                    let valid = (
+                        Temperature::try_new_with_limits(temperature, &limits).is_ok(),
+                        RelativeHumidity::try_from_percent_with_limits(relative_humidity, &limits)
+                            .is_ok(),
+                    );
+
+                    assert_eq!(expected, valid);
+                }
+             )*
+        }
+    }
+
+    limits_tests! {
+        default_limits: (Limits::default(), (-100.0, 1.0), (true, true)),
+        default_rejects_dry_air: (Limits::default(), (18.0, 0.0), (true, false)),
+        narrowed: (Limits::default().temperature(-40.0, 30.0), (30.0, 50.0), (true, true)),
+        narrowed_rejects: (Limits::default().temperature(-40.0, 30.0), (35.0, 50.0), (false, true)),
+        widened: (
+            Limits::default().temperature(-120.0, 120.0).relative_humidity(0.0, 100.0),
+            (-110.0, 0.0),
+            (true, true)
+        ),
+    }
+
+    #[test]
+    #[should_panic(expected = "relative humidity limits")]
+    fn relative_humidity_above_saturation() {
+        Limits::default().relative_humidity(0.0, 110.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "temperature limits")]
+    fn temperature_min_above_max() {
+        Limits::default().temperature(30.0, -40.0);
+    }
+
+    macro_rules! try_temperature_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let ((min, max), expected) = $value;
+
+                    let result = Limits::default().try_temperature(min, max);
+
+                    assert_eq!(expected, result.map_err(|error| error.to_string()).err());
+                }
+             )*
+        }
+    }
+
+    try_temperature_tests! {
+        try_temperature_lowest: ((-200.0, 30.0), None),
+        try_temperature_below_singularity: (
+            (-250.0, 30.0),
+            Some("temperature must be at least -200, got -250".to_string())
+        ),
+        try_temperature_min_above_max: (
+            (30.0, -40.0),
+            Some("temperature must be at least 30, got -40".to_string())
+        ),
+        try_temperature_infinite: (
+            (-40.0, f64::INFINITY),
+            Some("temperature must be a finite number of at least -40, got inf".to_string())
+        ),
+    }
+
+    #[test]
+    #[should_panic(expected = "temperature limits")]
+    fn temperature_below_singularity() {
+        Limits::default().temperature(-250.0, 30.0);
+    }
+
+    #[test]
+    fn dew_point_of_dry_air() {
+        let limits = Limits::default().relative_humidity(0.0, 100.0);
+        let measurement = Measurement::new(
+            Temperature::new(18.0),
+            RelativeHumidity::try_from_percent_with_limits(0.0, &limits).unwrap(),
+        );
+
+        assert_eq!(
+            "-243.04",
+            format!("{:.2}", measurement.calculate_dew_point())
+        );
+    }
+
+    macro_rules! open_window_result_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (temperature, relative_humidity) = $value;
+                    let limits = Limits::default()
+                        .temperature(-150.0, 150.0)
+                        .relative_humidity(0.0, 100.0);
+                    let measurement = Measurement::new(
+                        Temperature::try_new_with_limits(temperature, &limits).unwrap(),
+                        RelativeHumidity::try_from_percent_with_limits(relative_humidity, &limits)
+                            .unwrap(),
+                    )
+                    .with_tolerance(SensorTolerance::new(0.5, 3.0));
+
+                    let result = open_window_result(&measurement, &measurement);
+                    let estimate = measurement.calculate_dew_point_estimate();
+
+                    assert_eq!(measurement.calculate_dew_point(), result.indoor_dew_point);
+                    assert!(estimate.min <= estimate.value && estimate.value <= estimate.max);
+                }
+             )*
+        }
+    }
+
+    open_window_result_tests! {
+        open_window_result_of_dry_air: (18.0, 0.0),
+        open_window_result_above_default_limits: (150.0, 50.0),
+        open_window_result_below_default_limits: (-150.0, 50.0),
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        let limits: Limits = serde_json::from_str(
+            r#"{"min_temperature":-40,"max_temperature":30,"min_relative_humidity":0,"max_relative_humidity":100}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Limits::default()
+                .temperature(-40.0, 30.0)
+                .relative_humidity(0.0, 100.0),
+            limits
+        );
+        assert_eq!(
+            limits,
+            serde_json::from_value(serde_json::to_value(&limits).unwrap()).unwrap()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_invalid() {
+        let error = serde_json::from_str::<Limits>(
+            r#"{"min_temperature":50,"max_temperature":-50,"min_relative_humidity":-10,"max_relative_humidity":500}"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            "temperature must be at least 50, got -50",
+            error.to_string()
+        );
+    }
+}
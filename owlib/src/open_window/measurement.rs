@@ -1,31 +1,137 @@
-use super::{relative_humidity::RelativeHumidity, temperature::Temperature};
+use crate::{psychrometrics::relative_humidity_from_dew_point, Error};
 
-/// Holds a temperature and relative humidity values.
+use super::{
+    dew_point::DewPointFormula,
+    pressure::Pressure,
+    relative_humidity::RelativeHumidity,
+    temperature::Temperature,
+    uncertainty::{dew_point_estimate, Estimate, SensorTolerance},
+};
+
+/// Holds a temperature and relative humidity values, optionally accompanied by
+/// barometric pressure and accuracy of the sensor.
+///
+/// More optional fields may be added in the future, so the struct can only be
+/// created with [Measurement::new] (or [Measurement::from_dew_point]), followed
+/// by setters of the optional fields.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct Measurement {
     /// Temperature.
     pub temperature: Temperature,
     /// Relative humidity.
     pub relative_humidity: RelativeHumidity,
+    /// Barometric pressure. Pressure dependent calculations assume standard
+    /// sea level pressure if it is not provided.
+    pub pressure: Option<Pressure>,
+    /// Accuracy of the sensor. Measured values are treated as exact if it is
+    /// not provided.
+    pub tolerance: Option<SensorTolerance>,
 }
 
 impl Measurement {
+    /// Creates a new `Measurement` struct without barometric pressure and
+    /// sensor tolerance.
+    ///
+    /// Optional fields can be set afterwards:
+    ///
+    /// ```
+    /// use owlib::open_window::measurement::Measurement;
+    /// use owlib::open_window::pressure::Pressure;
+    /// use owlib::open_window::relative_humidity::RelativeHumidity;
+    /// use owlib::open_window::temperature::Temperature;
+    /// use owlib::open_window::uncertainty::SensorTolerance;
+    ///
+    /// let measurement = Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55))
+    ///     .with_pressure(Pressure::from_altitude(1500.0).unwrap())
+    ///     .with_tolerance(SensorTolerance::new(0.5, 3.0));
+    /// ```
+    pub fn new(temperature: Temperature, relative_humidity: RelativeHumidity) -> Self {
+        Self {
+            temperature,
+            relative_humidity,
+            pressure: None,
+            tolerance: None,
+        }
+    }
+
+    /// Sets barometric pressure.
+    pub fn with_pressure(mut self, pressure: Pressure) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+
+    /// Sets accuracy of the sensor.
+    pub fn with_tolerance(mut self, tolerance: SensorTolerance) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Creates a new `Measurement` struct from a temperature and a dew point
+    /// (°C), e.g. published by a weather feed.
+    ///
+    /// Returns an [Error] if the dew point does not yield a valid relative
+    /// humidity at provided temperature.
+    pub fn from_dew_point(temperature: Temperature, dew_point: f64) -> Result<Self, Error> {
+        let relative_humidity = relative_humidity_from_dew_point(&temperature, dew_point)?;
+
+        Ok(Self::new(temperature, relative_humidity))
+    }
+
     /// Calculates a dew point based on temperature and relative humidity values
     /// held by this `Measurement` struct.
+    ///
+    /// Uses Magnus formula with Alduchov-Eskridge coefficients
+    /// ([DewPointFormula::AlduchovEskridge]).
     pub fn calculate_dew_point(&self) -> f64 {
-        // 243.04*(LN(RH/100)+((17.625*T)/(243.04+T)))/(17.625-LN(RH/100)-((17.625*T)/(243.04+T)))
-        243.04
-            * ((self.relative_humidity.value() as f64 / 100.0).ln()
-                + ((17.625 * self.temperature.value()) / (243.04 + self.temperature.value())))
-            / (17.625
-                - (self.relative_humidity.value() as f64 / 100.0).ln()
-                - ((17.625 * self.temperature.value()) / (243.04 + self.temperature.value())))
+        self.calculate_dew_point_with(DewPointFormula::default())
+    }
+
+    /// Calculates a dew point based on temperature and relative humidity values
+    /// held by this `Measurement` struct, using provided formula.
+    pub fn calculate_dew_point_with(&self, formula: DewPointFormula) -> f64 {
+        let vapour_pressure = self.relative_humidity.percent() / 100.0
+            * formula.saturation_vapour_pressure(self.temperature.value());
+
+        formula.dew_point(vapour_pressure)
+    }
+
+    /// Calculates a dew point together with an interval it falls into, given
+    /// the sensor tolerance held by this `Measurement` struct.
+    ///
+    /// The interval is empty (`min` and `max` equal the value) if there is no
+    /// sensor tolerance.
+    pub fn calculate_dew_point_estimate(&self) -> Estimate {
+        match &self.tolerance {
+            Some(tolerance) => dew_point_estimate(
+                self.temperature.value(),
+                self.relative_humidity.percent(),
+                tolerance,
+            ),
+            None => Estimate::exact(self.calculate_dew_point()),
+        }
+    }
+
+    /// Calculates a frost point - temperature at which water vapour deposits as
+    /// ice - using [DewPointFormula::WmoOverIce].
+    ///
+    /// Returns `None` if the temperature is not below freezing, as then a dew
+    /// point is the relevant value.
+    pub fn calculate_frost_point(&self) -> Option<f64> {
+        if self.temperature.value() >= 0.0 {
+            return None;
+        }
+
+        Some(self.calculate_dew_point_with(DewPointFormula::WmoOverIce))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::open_window::{relative_humidity::RelativeHumidity, temperature::Temperature};
+    use crate::open_window::{
+        dew_point::DewPointFormula, relative_humidity::RelativeHumidity, temperature::Temperature,
+    };
 
     use super::Measurement;
 
@@ -36,10 +142,10 @@ mod tests {
                 fn $name() {
                     let (temperature, relative_humidity, expected) = $value;
 
-                    let measurement = Measurement {
-                        temperature: Temperature::new(temperature),
-                        relative_humidity: RelativeHumidity::new(relative_humidity),
-                    };
+                    let measurement = Measurement::new(
+                        Temperature::new(temperature),
+                        RelativeHumidity::new(relative_humidity),
+                    );
 
                     let dew_point = measurement.calculate_dew_point();
 
@@ -54,4 +160,114 @@ mod tests {
         outdoor: (-5.0, 80, "-7.92"),
         zero: (0.0, 1, "-50.35"),
     }
+
+    macro_rules! dew_point_with_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (formula, temperature, relative_humidity, expected) = $value;
+
+                    let measurement = Measurement::new(
+                        Temperature::new(temperature),
+                        RelativeHumidity::new(relative_humidity),
+                    );
+
+                    let dew_point = measurement.calculate_dew_point_with(formula);
+
+                    assert_eq!(expected, format!("{dew_point:.2}"));
+                }
+             )*
+        }
+    }
+
+    dew_point_with_tests! {
+        magnus_tetens: (DewPointFormula::MagnusTetens, -20.0, 60, "-25.74"),
+        alduchov_eskridge: (DewPointFormula::AlduchovEskridge, -20.0, 60, "-25.78"),
+        arden_buck: (DewPointFormula::ArdenBuck, 60.0, 40, "41.46"),
+        sonntag: (DewPointFormula::Sonntag, 60.0, 40, "41.54"),
+        wmo_over_ice: (DewPointFormula::WmoOverIce, -20.0, 60, "-23.21"),
+    }
+
+    #[test]
+    fn frost_point_below_freezing() {
+        let measurement = Measurement::new(Temperature::new(-5.0), RelativeHumidity::new(80));
+
+        let frost_point = measurement.calculate_frost_point().unwrap();
+
+        assert_eq!("-7.01", format!("{frost_point:.2}"));
+    }
+
+    #[test]
+    fn frost_point_above_freezing() {
+        let measurement = Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+
+        assert_eq!(None, measurement.calculate_frost_point());
+    }
+
+    #[test]
+    fn fractional_relative_humidity() {
+        let measurement =
+            Measurement::new(Temperature::new(18.0), RelativeHumidity::from_percent(55.4));
+
+        let dew_point = measurement.calculate_dew_point();
+
+        assert_eq!("8.93", format!("{dew_point:.2}"));
+    }
+
+    #[test]
+    fn from_dew_point() {
+        let measurement = Measurement::from_dew_point(Temperature::new(18.0), 8.82).unwrap();
+
+        assert_eq!(
+            "54.98",
+            format!("{:.2}", measurement.relative_humidity.percent())
+        );
+        assert_eq!("8.82", format!("{:.2}", measurement.calculate_dew_point()));
+    }
+
+    #[test]
+    fn from_dew_point_above_temperature() {
+        assert!(Measurement::from_dew_point(Temperature::new(18.0), 19.0).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        let measurement: Measurement =
+            serde_json::from_str(r#"{ "temperature": 18.0, "relative_humidity": 55.4 }"#).unwrap();
+
+        assert_eq!(
+            Measurement::new(Temperature::new(18.0), RelativeHumidity::from_percent(55.4)),
+            measurement
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_invalid() {
+        let error = serde_json::from_str::<Measurement>(
+            r#"{ "temperature": 18.0, "relative_humidity": 0.0 }"#,
+        )
+        .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("relative humidity must be at least 1, got 0"));
+        assert!(serde_json::from_str::<Measurement>(
+            r#"{ "temperature": 180.0, "relative_humidity": 50.0 }"#
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let measurement = Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+
+        assert_eq!(
+            r#"{"temperature":18.0,"relative_humidity":55.0,"pressure":null,"tolerance":null}"#,
+            serde_json::to_string(&measurement).unwrap()
+        );
+    }
 }
@@ -1,14 +1,73 @@
-use self::measurement::Measurement;
+use alloc::vec::Vec;
+
+use self::{
+    measurement::Measurement,
+    policy::DecisionPolicy,
+    uncertainty::Confidence,
+    verdict::{Reason, Verdict},
+};
+
+/// Modules holds `AirState` caching properties calculated from a `Measurement`.
+pub mod air_state;
+
+/// Modules holds functions evaluating large series of measurements at once.
+pub mod batch;
+
+/// Modules holds `Calibration` correcting readings of a drifting sensor.
+pub mod calibration;
+
+/// Modules holds thermal comfort indices - heat index, humidex and apparent temperature.
+pub mod comfort;
+
+/// Modules holds functions assessing condensation and mould risk on cold surfaces.
+pub mod condensation;
+
+/// Modules holds formulas used for calculating dew and frost points.
+pub mod dew_point;
+
+/// Modules holds functions finding the best time to air a room within a forecast.
+pub mod forecast;
+
+/// Modules holds functions evaluating many rooms sharing one outdoor measurement.
+pub mod house;
+
+/// Modules holds configurable `Limits` of accepted temperature and relative humidity.
+pub mod limits;
 
 /// Modules holds necessary structures and functions for creating `Measurement`.
 pub mod measurement;
 
+/// Modules holds checks flagging implausible sensor readings.
+pub mod plausibility;
+
+/// Modules holds `DecisionPolicy` allowing to fine tune the "open window" verdict.
+pub mod policy;
+
+/// Modules holds necessary structures and functions for creating `Pressure`.
+pub mod pressure;
+
 /// Modules holds necessary structures and functions for creating `RelativeHumidity`.
 pub mod relative_humidity;
 
+/// Modules holds necessary structures describing a ventilated `Room`.
+pub mod room;
+
+/// Modules holds `VentilationStrategy` trait allowing to pick a decision algorithm.
+pub mod strategy;
+
 /// Modules holds necesarry structures and functions for creating `Temperature`.
 pub mod temperature;
 
+/// Modules holds structures describing sensor accuracy and its impact on the
+/// calculation results.
+pub mod uncertainty;
+
+/// Modules holds `Verdict` and `Reason` explaining the "open window" verdict.
+pub mod verdict;
+
+/// Modules holds functions predicting effects of airing a room.
+pub mod ventilation;
+
 /// Answers a question whether one should open windows in order to decrease
 /// indoor humidity.
 ///
@@ -25,17 +84,11 @@ pub mod temperature;
 ///
 /// let indoor_temperature = Temperature::new(18.0);
 /// let indoor_humidity = RelativeHumidity::new(50);
-/// let indoor_measurement = Measurement {
-///     temperature: indoor_temperature,
-///     relative_humidity: indoor_humidity,
-/// };
+/// let indoor_measurement = Measurement::new(indoor_temperature, indoor_humidity);
 ///
 /// let outdoor_temperature = Temperature::new(1.0);
 /// let outdoor_humidity = RelativeHumidity::new(85);
-/// let outdoor_measurement = Measurement {
-///     temperature: outdoor_temperature,
-///     relative_humidity: outdoor_humidity,
-/// };
+/// let outdoor_measurement = Measurement::new(outdoor_temperature, outdoor_humidity);
 ///
 /// let open_window = open_window(&indoor_measurement, &outdoor_measurement);
 /// ```
@@ -44,13 +97,21 @@ pub fn open_window(indoor_measurement: &Measurement, outdoor_measurement: &Measu
 }
 
 /// Holds detailed calculation results performed while obtaining "open window" verdict.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OpenWindowResult {
     /// Indoor dew point.
     pub indoor_dew_point: f64,
     /// Outdoor dew point.
     pub outdoor_dew_point: f64,
-    /// Open window verdict.
+    /// Open window verdict. `true` only if `verdict` is [Verdict::Open].
     pub open_window: bool,
+    /// Open window verdict.
+    pub verdict: Verdict,
+    /// Reasons explaining the verdict.
+    pub reasons: Vec<Reason>,
+    /// Confidence of the verdict, given sensor tolerances.
+    pub confidence: Confidence,
 }
 
 /// Answers a question whether one should open windows in order to decrease
@@ -59,19 +120,14 @@ pub struct OpenWindowResult {
 /// The decision is made by comparing indoor and outdoor dew points, calculated
 /// from indoor/outdoor measurements.
 ///
+/// Uses the default, zero margin [DecisionPolicy]. See [DecisionPolicy::evaluate]
+/// for a verdict requiring a minimum dew point difference.
+///
 /// As opposed to [open_window::open_window] this method - beside "open window" verdict -
 /// also returns intermediate calculations steps - indoor / outdoor dew points.
 pub fn open_window_result(
     indoor_measurement: &Measurement,
     outdoor_measurement: &Measurement,
 ) -> OpenWindowResult {
-    let indoor_dew_point = indoor_measurement.calculate_dew_point();
-    let outdoor_dew_point = outdoor_measurement.calculate_dew_point();
-    let open_window = indoor_dew_point > outdoor_dew_point;
-
-    OpenWindowResult {
-        indoor_dew_point,
-        outdoor_dew_point,
-        open_window,
-    }
+    DecisionPolicy::default().evaluate(indoor_measurement, outdoor_measurement, None)
 }
@@ -0,0 +1,253 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::math;
+
+use super::{measurement::Measurement, relative_humidity::MAX_HUMIDITY, strategy::StrategyContext};
+
+/// A symptom of a faulty or drifting sensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Implausibility {
+    /// Relative humidity reads 100% - the sensor may be wet or stuck.
+    Saturated,
+    /// Reported dew point is above air temperature, which is physically impossible.
+    DewPointAboveTemperature,
+    /// Temperature changed more than allowed since the previous reading.
+    TemperatureJump,
+    /// Relative humidity changed more than allowed since the previous reading.
+    RelativeHumidityJump,
+}
+
+impl Implausibility {
+    /// Returns a machine-readable code of the implausibility.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Saturated => "saturated",
+            Self::DewPointAboveTemperature => "dew_point_above_temperature",
+            Self::TemperatureJump => "temperature_jump",
+            Self::RelativeHumidityJump => "relative_humidity_jump",
+        }
+    }
+
+    /// Returns a human-readable message describing the implausibility.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::Saturated => "Relative humidity sensor is saturated.",
+            Self::DewPointAboveTemperature => "Dew point is above air temperature.",
+            Self::TemperatureJump => "Temperature changed suspiciously fast.",
+            Self::RelativeHumidityJump => "Relative humidity changed suspiciously fast.",
+        }
+    }
+}
+
+impl fmt::Display for Implausibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+/// Describes which readings are considered implausible.
+///
+/// Jump limits apply to consecutive readings, so they should match the
+/// sampling interval of the sensor.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct PlausibilityCheck {
+    /// Maximum change of temperature (°C) between consecutive readings.
+    pub max_temperature_jump: f64,
+    /// Maximum change of relative humidity (percentage points) between
+    /// consecutive readings.
+    pub max_relative_humidity_jump: f64,
+    /// Whether relative humidity of 100% is implausible on its own. It is
+    /// a normal reading in fog or rain, so by default it is only flagged
+    /// together with a reported dew point above air temperature.
+    pub flag_saturated: bool,
+}
+
+impl Default for PlausibilityCheck {
+    /// Limits suitable for readings taken every few minutes.
+    fn default() -> Self {
+        Self {
+            max_temperature_jump: 5.0,
+            max_relative_humidity_jump: 20.0,
+            flag_saturated: false,
+        }
+    }
+}
+
+impl PlausibilityCheck {
+    /// Checks a `Measurement`, optionally against the previous reading of the
+    /// same sensor and a dew point (°C) reported alongside it, e.g. by
+    /// a weather station which calculates it on its own. Returns an empty `Vec`
+    /// if the reading looks plausible.
+    ///
+    /// A reported dew point above air temperature is never plausible. Together
+    /// with relative humidity of 100% it is a typical symptom of a wet sensor,
+    /// so the reading is then flagged as [Implausibility::Saturated] as well.
+    pub fn check(
+        &self,
+        measurement: &Measurement,
+        previous_measurement: Option<&Measurement>,
+        reported_dew_point: Option<f64>,
+    ) -> Vec<Implausibility> {
+        let mut implausibilities = Vec::new();
+
+        let saturated = measurement.relative_humidity.percent() >= MAX_HUMIDITY as f64;
+        let dew_point_above_temperature =
+            reported_dew_point.is_some_and(|dew_point| dew_point > measurement.temperature.value());
+
+        if saturated && (self.flag_saturated || dew_point_above_temperature) {
+            implausibilities.push(Implausibility::Saturated);
+        }
+
+        if dew_point_above_temperature {
+            implausibilities.push(Implausibility::DewPointAboveTemperature);
+        }
+
+        if let Some(previous) = previous_measurement {
+            let temperature_jump =
+                math::abs(measurement.temperature.value() - previous.temperature.value());
+            if temperature_jump > self.max_temperature_jump {
+                implausibilities.push(Implausibility::TemperatureJump);
+            }
+
+            let relative_humidity_jump = math::abs(
+                measurement.relative_humidity.percent() - previous.relative_humidity.percent(),
+            );
+            if relative_humidity_jump > self.max_relative_humidity_jump {
+                implausibilities.push(Implausibility::RelativeHumidityJump);
+            }
+        }
+
+        implausibilities
+    }
+
+    /// Checks whether either indoor or outdoor measurement is implausible,
+    /// given previous readings and reported dew points held by the context.
+    pub(crate) fn any_implausible(
+        &self,
+        indoor_measurement: &Measurement,
+        outdoor_measurement: &Measurement,
+        context: &StrategyContext,
+    ) -> bool {
+        !self
+            .check(
+                indoor_measurement,
+                context.previous_indoor_measurement.as_ref(),
+                context.reported_indoor_dew_point,
+            )
+            .is_empty()
+            || !self
+                .check(
+                    outdoor_measurement,
+                    context.previous_outdoor_measurement.as_ref(),
+                    context.reported_outdoor_dew_point,
+                )
+                .is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, relative_humidity::RelativeHumidity, temperature::Temperature,
+    };
+
+    use super::{Implausibility, PlausibilityCheck};
+
+    macro_rules! plausibility_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (check, (temperature, relative_humidity), previous, reported_dew_point, expected): (
+                        PlausibilityCheck,
+                        (f64, f64),
+                        Option<(f64, f64)>,
+                        Option<f64>,
+                        Vec<Implausibility>,
+                    ) = $value;
+
+                    let measurement = |temperature, relative_humidity| {
+                        Measurement::new(
+                            Temperature::new(temperature),
+                            RelativeHumidity::from_percent(relative_humidity),
+                        )
+                    };
+
+                    let previous_measurement = previous
+                        .map(|(temperature, relative_humidity)| measurement(temperature, relative_humidity));
+
+                    let implausibilities = check.check(
+                        &measurement(temperature, relative_humidity),
+                        previous_measurement.as_ref(),
+                        reported_dew_point,
+                    );
+
+                    assert_eq!(expected, implausibilities);
+                }
+             )*
+        }
+    }
+
+    plausibility_tests! {
+        plausible: (PlausibilityCheck::default(), (18.0, 55.0), Some((17.5, 52.0)), Some(8.8), vec![]),
+        without_previous: (PlausibilityCheck::default(), (18.0, 55.0), None, None, vec![]),
+        saturated_in_fog: (PlausibilityCheck::default(), (5.0, 100.0), None, Some(5.0), vec![]),
+        saturated_flagged: (
+            PlausibilityCheck { flag_saturated: true, ..Default::default() },
+            (5.0, 100.0),
+            None,
+            None,
+            vec![Implausibility::Saturated]
+        ),
+        saturated_with_dew_point_above_temperature: (
+            PlausibilityCheck::default(),
+            (5.0, 100.0),
+            None,
+            Some(6.0),
+            vec![Implausibility::Saturated, Implausibility::DewPointAboveTemperature]
+        ),
+        dew_point_above_temperature: (
+            PlausibilityCheck::default(),
+            (10.0, 80.0),
+            None,
+            Some(11.0),
+            vec![Implausibility::DewPointAboveTemperature]
+        ),
+        temperature_jump: (
+            PlausibilityCheck::default(),
+            (18.0, 55.0),
+            Some((10.0, 55.0)),
+            None,
+            vec![Implausibility::TemperatureJump]
+        ),
+        relative_humidity_jump: (
+            PlausibilityCheck::default(),
+            (18.0, 95.0),
+            Some((18.0, 55.0)),
+            None,
+            vec![Implausibility::RelativeHumidityJump]
+        ),
+        saturated_after_jump: (
+            PlausibilityCheck { flag_saturated: true, ..Default::default() },
+            (10.0, 100.0),
+            Some((18.0, 55.0)),
+            None,
+            vec![
+                Implausibility::Saturated,
+                Implausibility::TemperatureJump,
+                Implausibility::RelativeHumidityJump
+            ]
+        ),
+    }
+}
@@ -0,0 +1,491 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Error, Field};
+
+use super::{
+    measurement::Measurement,
+    plausibility::PlausibilityCheck,
+    relative_humidity::RelativeHumidity,
+    strategy::StrategyContext,
+    temperature::Temperature,
+    uncertainty::Confidence,
+    verdict::{Reason, Verdict},
+    OpenWindowResult,
+};
+
+/// Describes how strict the "open window" verdict should be.
+///
+/// Default policy opens windows whenever indoor dew point is higher than
+/// outdoor dew point, which is what [super::open_window_result] does.
+///
+/// # Example
+///
+/// ```
+/// use owlib::open_window::measurement::Measurement;
+/// use owlib::open_window::policy::DecisionPolicy;
+/// use owlib::open_window::relative_humidity::RelativeHumidity;
+/// use owlib::open_window::temperature::Temperature;
+///
+/// let policy = DecisionPolicy::default()
+///     .min_dew_point_delta(1.0)
+///     .hysteresis(0.5)
+///     .min_outdoor_temperature(Temperature::new(-10.0));
+///
+/// let indoor_measurement = Measurement::new(Temperature::new(18.0), RelativeHumidity::new(50));
+///
+/// let outdoor_measurement = Measurement::new(Temperature::new(1.0), RelativeHumidity::new(85));
+///
+/// let result = policy.evaluate(&indoor_measurement, &outdoor_measurement, Some(false));
+///
+/// assert!(result.open_window);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DecisionPolicy {
+    min_dew_point_delta: f64,
+    hysteresis: Option<f64>,
+    min_outdoor_temperature: Option<Temperature>,
+    max_outdoor_temperature: Option<Temperature>,
+    min_indoor_relative_humidity: Option<RelativeHumidity>,
+    plausibility_check: PlausibilityCheck,
+}
+
+impl DecisionPolicy {
+    /// Sets minimum difference (°C) by which indoor dew point has to exceed
+    /// outdoor dew point in order to open windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is negative or not finite.
+    pub fn min_dew_point_delta(self, delta: f64) -> Self {
+        match self.try_min_dew_point_delta(delta) {
+            Ok(policy) => policy,
+            Err(error) => panic!("invalid decision policy: {error}"),
+        }
+    }
+
+    /// Sets minimum difference (°C) by which indoor dew point has to exceed
+    /// outdoor dew point in order to open windows.
+    ///
+    /// As opposed to [DecisionPolicy::min_dew_point_delta] function it does
+    /// not panic, but returns an [Error] instead.
+    pub fn try_min_dew_point_delta(mut self, delta: f64) -> Result<Self, Error> {
+        self.min_dew_point_delta = Error::validate_min(Field::DewPointDelta, delta, 0.0)?;
+        Ok(self)
+    }
+
+    /// Sets width (°C) of a hysteresis band. Once windows are open, they stay
+    /// open until the dew point difference drops below the minimum dew point
+    /// delta decreased by the band, so noisy readings do not flip the verdict.
+    /// With zero margin windows stay open even if outdoor dew point slightly
+    /// exceeds indoor dew point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is negative or not finite.
+    pub fn hysteresis(self, band: f64) -> Self {
+        match self.try_hysteresis(band) {
+            Ok(policy) => policy,
+            Err(error) => panic!("invalid decision policy: {error}"),
+        }
+    }
+
+    /// Sets width (°C) of a hysteresis band.
+    ///
+    /// As opposed to [DecisionPolicy::hysteresis] function it does not panic,
+    /// but returns an [Error] instead.
+    pub fn try_hysteresis(mut self, band: f64) -> Result<Self, Error> {
+        self.hysteresis = Some(Error::validate_min(Field::Hysteresis, band, 0.0)?);
+        Ok(self)
+    }
+
+    /// Keeps windows closed if outdoor temperature is below provided limit.
+    pub fn min_outdoor_temperature(mut self, temperature: Temperature) -> Self {
+        self.min_outdoor_temperature = Some(temperature);
+        self
+    }
+
+    /// Keeps windows closed if outdoor temperature is above provided limit.
+    pub fn max_outdoor_temperature(mut self, temperature: Temperature) -> Self {
+        self.max_outdoor_temperature = Some(temperature);
+        self
+    }
+
+    /// Skips opening windows if indoor relative humidity is at or below
+    /// provided level, as there is no need to.
+    pub fn min_indoor_relative_humidity(mut self, relative_humidity: RelativeHumidity) -> Self {
+        self.min_indoor_relative_humidity = Some(relative_humidity);
+        self
+    }
+
+    /// Replaces the default [PlausibilityCheck], which lowers confidence of
+    /// the verdict to [Confidence::Implausible] for implausible readings.
+    pub fn plausibility_check(mut self, plausibility_check: PlausibilityCheck) -> Self {
+        self.plausibility_check = plausibility_check;
+        self
+    }
+
+    /// Answers a question whether one should open windows in order to decrease
+    /// indoor humidity, following this policy.
+    ///
+    /// `previous_open_window` is the previously returned verdict, if any. It is
+    /// only taken into account if the policy defines a hysteresis band.
+    pub fn evaluate(
+        &self,
+        indoor_measurement: &Measurement,
+        outdoor_measurement: &Measurement,
+        previous_open_window: Option<bool>,
+    ) -> OpenWindowResult {
+        let context = StrategyContext {
+            previous_open_window,
+            ..Default::default()
+        };
+
+        self.evaluate_in_context(indoor_measurement, outdoor_measurement, &context)
+    }
+
+    /// Answers a question whether one should open windows in order to decrease
+    /// indoor humidity, following this policy.
+    ///
+    /// As opposed to [DecisionPolicy::evaluate] function it also checks the
+    /// measurements against previous readings and reported dew points held by
+    /// the context.
+    pub fn evaluate_in_context(
+        &self,
+        indoor_measurement: &Measurement,
+        outdoor_measurement: &Measurement,
+        context: &StrategyContext,
+    ) -> OpenWindowResult {
+        let indoor_dew_point = indoor_measurement.calculate_dew_point_estimate();
+        let outdoor_dew_point = outdoor_measurement.calculate_dew_point_estimate();
+
+        let confidence = if self.plausibility_check.any_implausible(
+            indoor_measurement,
+            outdoor_measurement,
+            context,
+        ) {
+            Confidence::Implausible
+        } else if indoor_dew_point.overlaps(&outdoor_dew_point) {
+            Confidence::WithinSensorError
+        } else {
+            Confidence::Certain
+        };
+
+        let indoor_dew_point = indoor_dew_point.value;
+        let outdoor_dew_point = outdoor_dew_point.value;
+
+        let (verdict, mut reasons) = delta_verdict(
+            indoor_dew_point - outdoor_dew_point,
+            self.min_dew_point_delta,
+            self.hysteresis,
+            context.previous_open_window,
+        );
+
+        let verdict = if self.too_cold(&outdoor_measurement.temperature) {
+            reasons.push(Reason::TooColdOutside);
+            Verdict::Close
+        } else if self.too_hot(&outdoor_measurement.temperature) {
+            reasons.push(Reason::TooHotOutside);
+            Verdict::Close
+        } else if verdict == Verdict::Open && self.dry_enough(&indoor_measurement.relative_humidity)
+        {
+            reasons.push(Reason::IndoorAlreadyDry);
+            Verdict::Neutral
+        } else {
+            verdict
+        };
+
+        if indoor_dew_point >= outdoor_measurement.temperature.value() {
+            reasons.push(Reason::CondensationRisk);
+        }
+
+        OpenWindowResult {
+            indoor_dew_point,
+            outdoor_dew_point,
+            open_window: verdict == Verdict::Open,
+            verdict,
+            reasons,
+            confidence,
+        }
+    }
+
+    fn too_cold(&self, temperature: &Temperature) -> bool {
+        self.min_outdoor_temperature
+            .as_ref()
+            .is_some_and(|min| temperature.value() < min.value())
+    }
+
+    fn too_hot(&self, temperature: &Temperature) -> bool {
+        self.max_outdoor_temperature
+            .as_ref()
+            .is_some_and(|max| temperature.value() > max.value())
+    }
+
+    fn dry_enough(&self, relative_humidity: &RelativeHumidity) -> bool {
+        self.min_indoor_relative_humidity
+            .as_ref()
+            .is_some_and(|min| relative_humidity.percent() <= min.percent())
+    }
+}
+
+/// Picks a verdict based on a difference between indoor and outdoor humidity
+/// (e.g. dew points), given a minimum difference and an optional hysteresis
+/// band, both expressed in the same unit as the difference.
+pub(crate) fn delta_verdict(
+    delta: f64,
+    min_delta: f64,
+    hysteresis: Option<f64>,
+    previous_open_window: Option<bool>,
+) -> (Verdict, Vec<Reason>) {
+    let (threshold, within_band) = match (hysteresis, previous_open_window) {
+        (Some(hysteresis), Some(true)) => (min_delta - hysteresis, true),
+        _ => (min_delta, false),
+    };
+
+    if within_band && delta <= 0.0 && delta > threshold {
+        (
+            Verdict::Open,
+            vec![Reason::OutdoorWetter, Reason::WithinHysteresis],
+        )
+    } else if delta <= 0.0 {
+        (Verdict::Close, vec![Reason::OutdoorWetter])
+    } else if delta <= threshold {
+        (
+            Verdict::Neutral,
+            vec![Reason::OutdoorDrier, Reason::DeltaBelowMargin],
+        )
+    } else if within_band && delta <= min_delta {
+        (
+            Verdict::Open,
+            vec![Reason::OutdoorDrier, Reason::WithinHysteresis],
+        )
+    } else {
+        (Verdict::Open, vec![Reason::OutdoorDrier])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, relative_humidity::RelativeHumidity, strategy::StrategyContext,
+        temperature::Temperature, uncertainty::Confidence,
+    };
+
+    use crate::open_window::verdict::{Reason, Verdict};
+
+    use super::DecisionPolicy;
+
+    macro_rules! policy_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (policy, (outdoor_temperature, outdoor_humidity), previous_open_window, expected) =
+                        $value;
+
+                    // Indoor dew point: 8.82 °C.
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+
+                    let outdoor_measurement = Measurement::new(
+                        Temperature::new(outdoor_temperature),
+                        RelativeHumidity::new(outdoor_humidity),
+                    );
+
+                    let result = policy.evaluate(
+                        &indoor_measurement,
+                        &outdoor_measurement,
+                        previous_open_window,
+                    );
+
+                    assert_eq!(expected, (result.verdict, result.reasons));
+                    assert_eq!(result.verdict == Verdict::Open, result.open_window);
+                }
+             )*
+        }
+    }
+
+    // Outdoor dew point at (10.0, 88) is 8.10 °C, at (0.0, 91) it is -1.29 °C.
+    policy_tests! {
+        zero_margin: (
+            DecisionPolicy::default(),
+            (10.0, 88),
+            None,
+            (Verdict::Open, vec![Reason::OutdoorDrier])
+        ),
+        outdoor_wetter: (
+            DecisionPolicy::default(),
+            (10.0, 100),
+            None,
+            (Verdict::Close, vec![Reason::OutdoorWetter])
+        ),
+        below_margin: (
+            DecisionPolicy::default().min_dew_point_delta(1.0),
+            (10.0, 88),
+            None,
+            (Verdict::Neutral, vec![Reason::OutdoorDrier, Reason::DeltaBelowMargin])
+        ),
+        above_margin: (
+            DecisionPolicy::default().min_dew_point_delta(1.0),
+            (0.0, 91),
+            None,
+            (Verdict::Open, vec![Reason::OutdoorDrier, Reason::CondensationRisk])
+        ),
+        hysteresis_keeps_open: (
+            DecisionPolicy::default().min_dew_point_delta(1.0).hysteresis(0.5),
+            (10.0, 88),
+            Some(true),
+            (Verdict::Open, vec![Reason::OutdoorDrier, Reason::WithinHysteresis])
+        ),
+        hysteresis_above_margin: (
+            DecisionPolicy::default().min_dew_point_delta(1.0).hysteresis(0.5),
+            (0.0, 91),
+            Some(true),
+            (Verdict::Open, vec![Reason::OutdoorDrier, Reason::CondensationRisk])
+        ),
+        hysteresis_keeps_closed: (
+            DecisionPolicy::default().min_dew_point_delta(1.0).hysteresis(0.5),
+            (10.0, 88),
+            Some(false),
+            (Verdict::Neutral, vec![Reason::OutdoorDrier, Reason::DeltaBelowMargin])
+        ),
+        // Outdoor dew point at (10.0, 94) is 9.08 °C, at (10.0, 100) it is 10.00 °C.
+        zero_margin_hysteresis_keeps_open: (
+            DecisionPolicy::default().hysteresis(1.0),
+            (10.0, 94),
+            Some(true),
+            (Verdict::Open, vec![Reason::OutdoorWetter, Reason::WithinHysteresis])
+        ),
+        zero_margin_hysteresis_closes: (
+            DecisionPolicy::default().hysteresis(1.0),
+            (10.0, 100),
+            Some(true),
+            (Verdict::Close, vec![Reason::OutdoorWetter])
+        ),
+        zero_margin_without_hysteresis: (
+            DecisionPolicy::default(),
+            (10.0, 94),
+            Some(true),
+            (Verdict::Close, vec![Reason::OutdoorWetter])
+        ),
+        zero_margin_hysteresis_keeps_closed: (
+            DecisionPolicy::default().hysteresis(1.0),
+            (10.0, 94),
+            Some(false),
+            (Verdict::Close, vec![Reason::OutdoorWetter])
+        ),
+        too_cold: (
+            DecisionPolicy::default().min_outdoor_temperature(Temperature::new(5.0)),
+            (0.0, 91),
+            None,
+            (
+                Verdict::Close,
+                vec![Reason::OutdoorDrier, Reason::TooColdOutside, Reason::CondensationRisk]
+            )
+        ),
+        too_hot: (
+            DecisionPolicy::default().max_outdoor_temperature(Temperature::new(5.0)),
+            (10.0, 88),
+            None,
+            (Verdict::Close, vec![Reason::OutdoorDrier, Reason::TooHotOutside])
+        ),
+        indoor_already_dry: (
+            DecisionPolicy::default().min_indoor_relative_humidity(RelativeHumidity::new(60)),
+            (10.0, 88),
+            None,
+            (Verdict::Neutral, vec![Reason::OutdoorDrier, Reason::IndoorAlreadyDry])
+        ),
+    }
+
+    macro_rules! plausibility_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let ((outdoor_temperature, outdoor_humidity), context, expected) = $value;
+
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+
+                    let outdoor_measurement = Measurement::new(
+                        Temperature::new(outdoor_temperature),
+                        RelativeHumidity::new(outdoor_humidity),
+                    );
+
+                    let result = DecisionPolicy::default().evaluate_in_context(
+                        &indoor_measurement,
+                        &outdoor_measurement,
+                        &context,
+                    );
+
+                    assert_eq!(expected, result.confidence);
+                }
+             )*
+        }
+    }
+
+    plausibility_tests! {
+        plausible: (
+            (0.0, 91),
+            StrategyContext {
+                previous_indoor_measurement: Some(Measurement::new(
+                    Temperature::new(18.5),
+                    RelativeHumidity::new(57),
+                )),
+                ..Default::default()
+            },
+            Confidence::Certain
+        ),
+        indoor_jump: (
+            (0.0, 91),
+            StrategyContext {
+                previous_indoor_measurement: Some(Measurement::new(
+                    Temperature::new(25.0),
+                    RelativeHumidity::new(55),
+                )),
+                ..Default::default()
+            },
+            Confidence::Implausible
+        ),
+        outdoor_fog: (
+            (5.0, 100),
+            StrategyContext {
+                reported_outdoor_dew_point: Some(5.0),
+                ..Default::default()
+            },
+            Confidence::Certain
+        ),
+        outdoor_wet_sensor: (
+            (5.0, 100),
+            StrategyContext {
+                reported_outdoor_dew_point: Some(7.0),
+                ..Default::default()
+            },
+            Confidence::Implausible
+        ),
+    }
+
+    #[test]
+    fn negative_min_dew_point_delta() {
+        let error = DecisionPolicy::default()
+            .try_min_dew_point_delta(-1.0)
+            .unwrap_err();
+
+        assert_eq!(
+            "dew point delta must be at least 0, got -1",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn negative_hysteresis() {
+        let error = DecisionPolicy::default().try_hysteresis(-1.0).unwrap_err();
+
+        assert_eq!("hysteresis must be at least 0, got -1", error.to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid decision policy: hysteresis must be a number")]
+    fn nan_hysteresis() {
+        DecisionPolicy::default().hysteresis(f64::NAN);
+    }
+}
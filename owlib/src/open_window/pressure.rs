@@ -0,0 +1,101 @@
+use crate::{math, Error, Field};
+
+/// Minimum value of barometric pressure
+pub const MIN_PRESSURE: f64 = 300.0;
+/// Maximum value of barometric pressure
+pub const MAX_PRESSURE: f64 = 1100.0;
+/// Standard atmospheric pressure at sea level
+pub const STANDARD_PRESSURE: f64 = 1013.25;
+
+/// Holds a barometric (station) pressure value expressed in hectopascals (hPa).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "f64", into = "f64")
+)]
+pub struct Pressure {
+    value: f64,
+}
+
+impl Pressure {
+    /// Creates new `Pressure` struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if provided value does not fall within following range `(300.0..=1100.0)`.
+    pub fn new(value: f64) -> Self {
+        match Self::try_new(value) {
+            Ok(pressure) => pressure,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Creates new `Pressure` struct.
+    ///
+    /// As opposed to [Pressure::new] function it does not panic, but returns
+    /// an [Error] instead.
+    pub fn try_new(value: f64) -> Result<Self, Error> {
+        let value = Error::validate(Field::Pressure, value, MIN_PRESSURE, MAX_PRESSURE)?;
+
+        Ok(Self { value })
+    }
+
+    /// Creates new `Pressure` struct from an altitude above sea level expressed
+    /// in metres, using International Standard Atmosphere barometric formula.
+    ///
+    /// Returns an [Error] if the altitude yields pressure outside of the allowed
+    /// range (roughly above 9000 m).
+    pub fn from_altitude(altitude: f64) -> Result<Self, Error> {
+        Self::try_new(STANDARD_PRESSURE * math::powf(1.0 - 2.25577e-5 * altitude, 5.25588))
+    }
+
+    /// Returns a pressure value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl Default for Pressure {
+    fn default() -> Self {
+        Self {
+            value: STANDARD_PRESSURE,
+        }
+    }
+}
+
+impl TryFrom<f64> for Pressure {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<Pressure> for f64 {
+    fn from(pressure: Pressure) -> Self {
+        pressure.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pressure;
+
+    #[test]
+    fn from_altitude() {
+        let pressure = Pressure::from_altitude(1500.0).unwrap();
+
+        assert_eq!("845.56", format!("{:.2}", pressure.value()));
+    }
+
+    #[test]
+    fn from_sea_level() {
+        assert_eq!(Pressure::default(), Pressure::from_altitude(0.0).unwrap());
+    }
+
+    #[test]
+    fn from_too_high_altitude() {
+        assert!(Pressure::from_altitude(10000.0).is_err());
+    }
+}
@@ -1,30 +1,29 @@
-use core::fmt;
-use std::error::Error;
+use crate::{math, Error};
 
-const VALIDATION_ERROR: &str = "relative humidity should be a percentage value between 1 and 100";
+use super::limits::Limits;
 
-/// Minimum value of relative humidity
+/// Minimum value of relative humidity accepted by default (see [Limits])
 pub const MIN_HUMIDITY: u8 = 1;
 
-/// Maximum value of relative humidity
+/// Maximum value of relative humidity accepted by default (see [Limits])
 pub const MAX_HUMIDITY: u8 = 100;
 
-/// An error returned by [RelativeHumidity::try_new] if provided value is invalid.
-#[derive(Debug)]
-pub struct RelativeHumidityInvalid(pub &'static str);
-
-impl Error for RelativeHumidityInvalid {}
-
-impl fmt::Display for RelativeHumidityInvalid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{VALIDATION_ERROR}")
-    }
-}
-
-/// Holds an integer value represeting relative humidity expressed as percentage (%).
+/// Holds a value represeting relative humidity expressed as percentage (%).
+///
+/// The value is stored with full precision, e.g. `55.4%`. Whole percentages can
+/// be provided with [RelativeHumidity::new], fractional ones with
+/// [RelativeHumidity::from_percent].
+///
+/// Accepted values depend on [Limits] - functions without a `limits` parameter
+/// and deserialization use the default ones.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "f64", into = "f64")
+)]
 pub struct RelativeHumidity {
-    value: u8,
+    value: f64,
 }
 
 impl RelativeHumidity {
@@ -32,41 +31,114 @@ impl RelativeHumidity {
     ///
     /// # Panics
     ///
-    /// Panics if provided value is not a valid relative humidity value (`(1..=100)`).
+    /// Panics if provided value does not fall within the default [Limits]
+    /// ([MIN_HUMIDITY]..=[MAX_HUMIDITY]).
     pub fn new(value: u8) -> Self {
-        if !Self::valid(value) {
-            panic!("{}", VALIDATION_ERROR);
-        }
-
-        Self { value }
+        Self::from_percent(value as f64)
     }
 
     /// Creates a new `RelativeHumidity` struct.
     ///
-    /// As opposed to [RelativeHumidity::new] function it does not panic, but returns a validation
-    /// error instead.
-    pub fn try_new(value: u8) -> Result<Self, RelativeHumidityInvalid> {
-        if !Self::valid(value) {
-            return Err(RelativeHumidityInvalid(VALIDATION_ERROR));
+    /// As opposed to [RelativeHumidity::new] function it does not panic, but returns an [Error]
+    /// instead.
+    pub fn try_new(value: u8) -> Result<Self, Error> {
+        Self::try_from_percent(value as f64)
+    }
+
+    /// Creates a new `RelativeHumidity` struct from a fractional percentage value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if provided value does not fall within the default [Limits]
+    /// ([MIN_HUMIDITY]..=[MAX_HUMIDITY]). Use
+    /// [RelativeHumidity::try_from_percent_with_limits] for a different range.
+    pub fn from_percent(value: f64) -> Self {
+        match Self::try_from_percent(value) {
+            Ok(relative_humidity) => relative_humidity,
+            Err(error) => panic!("{error}"),
         }
+    }
 
-        Ok(Self::new(value))
+    /// Creates a new `RelativeHumidity` struct from a fractional percentage value.
+    ///
+    /// As opposed to [RelativeHumidity::from_percent] function it does not panic, but returns
+    /// an [Error] instead.
+    pub fn try_from_percent(value: f64) -> Result<Self, Error> {
+        Self::try_from_percent_with_limits(value, &Limits::default())
     }
 
-    /// Returns a relative humidity value.
+    /// Creates a new `RelativeHumidity` struct from a fractional percentage value,
+    /// accepting values within provided limits instead of the default ones.
+    ///
+    /// Limits may allow completely dry air (0%), in which case dew point
+    /// calculations return the lowest value of the formula instead of -∞.
+    pub fn try_from_percent_with_limits(value: f64, limits: &Limits) -> Result<Self, Error> {
+        let value = limits.validate_relative_humidity(value)?;
+
+        Ok(Self { value })
+    }
+
+    /// Returns a relative humidity value rounded to a whole percentage.
+    ///
+    /// Use [RelativeHumidity::percent] in calculations, as it returns the
+    /// precise value.
     pub fn value(&self) -> u8 {
-        self.value
+        math::round(self.value) as u8
     }
 
-    fn valid(value: u8) -> bool {
-        (MIN_HUMIDITY..=MAX_HUMIDITY).contains(&value)
+    /// Returns a precise relative humidity value.
+    pub fn percent(&self) -> f64 {
+        self.value
     }
 }
 
 impl Default for RelativeHumidity {
     fn default() -> Self {
         Self {
-            value: MIN_HUMIDITY,
+            value: MIN_HUMIDITY as f64,
         }
     }
 }
+
+impl TryFrom<f64> for RelativeHumidity {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_from_percent(value)
+    }
+}
+
+impl From<RelativeHumidity> for f64 {
+    fn from(relative_humidity: RelativeHumidity) -> Self {
+        relative_humidity.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeHumidity;
+
+    #[test]
+    fn fractional_value() {
+        let relative_humidity = RelativeHumidity::try_from_percent(55.4).unwrap();
+
+        assert_eq!(55.4, relative_humidity.percent());
+        assert_eq!(55, relative_humidity.value());
+    }
+
+    #[test]
+    fn whole_value() {
+        assert_eq!(
+            RelativeHumidity::new(55),
+            RelativeHumidity::from_percent(55.0)
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert!(RelativeHumidity::try_from_percent(0.5).is_err());
+        assert!(RelativeHumidity::try_from_percent(100.1).is_err());
+        assert!(RelativeHumidity::try_from_percent(f64::NAN).is_err());
+        assert!(RelativeHumidity::try_new(0).is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{math, Error, Field};
+
+/// Minimum volume of a room expressed in cubic metres (m³).
+pub const MIN_VOLUME: f64 = 1.0;
+/// Minimum number of air changes per hour while the window is open.
+pub const MIN_AIR_CHANGES_PER_HOUR: f64 = 0.1;
+
+/// Describes a ventilated room.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Room {
+    volume: f64,
+    air_changes_per_hour: f64,
+    moisture_sources: f64,
+}
+
+impl Room {
+    /// Creates new `Room` struct.
+    ///
+    /// `air_changes_per_hour` is an estimated number of air changes per hour
+    /// while the window is open. A tilted window typically gives 1-3 air
+    /// changes per hour, while a wide open window gives 5-15, and cross
+    /// ventilation even more.
+    ///
+    /// `moisture_sources` is water vapour released into the room by its
+    /// occupants, plants, cooking etc., expressed in grams per hour (g/h).
+    ///
+    /// # Panics
+    ///
+    /// Panics if volume is below [MIN_VOLUME], number of air changes per hour
+    /// is below [MIN_AIR_CHANGES_PER_HOUR], moisture sources are negative, or
+    /// any of the values is not finite.
+    pub fn new(volume: f64, air_changes_per_hour: f64, moisture_sources: f64) -> Self {
+        match Self::try_new(volume, air_changes_per_hour, moisture_sources) {
+            Ok(room) => room,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Creates new `Room` struct.
+    ///
+    /// As opposed to [Room::new] function it does not panic, but returns
+    /// an [Error] instead.
+    pub fn try_new(
+        volume: f64,
+        air_changes_per_hour: f64,
+        moisture_sources: f64,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            volume: Error::validate_min(Field::Volume, volume, MIN_VOLUME)?,
+            air_changes_per_hour: Error::validate_min(
+                Field::AirChangesPerHour,
+                air_changes_per_hour,
+                MIN_AIR_CHANGES_PER_HOUR,
+            )?,
+            moisture_sources: Error::validate_min(Field::MoistureSources, moisture_sources, 0.0)?,
+        })
+    }
+
+    /// Returns volume of the room expressed in cubic metres (m³).
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+
+    /// Returns estimated number of air changes per hour while the window is open.
+    pub fn air_changes_per_hour(&self) -> f64 {
+        self.air_changes_per_hour
+    }
+
+    /// Returns water vapour released into the room, expressed in grams per hour (g/h).
+    pub fn moisture_sources(&self) -> f64 {
+        self.moisture_sources
+    }
+
+    /// Returns a fraction (`0.0..=1.0`) of the room's air replaced by outdoor
+    /// air after the window has been open for provided number of minutes,
+    /// assuming outdoor air is perfectly mixed with the indoor air.
+    pub fn exchanged_fraction(&self, minutes: f64) -> f64 {
+        1.0 - math::exp(-self.air_changes_per_hour * minutes.max(0.0) / 60.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Room;
+
+    #[test]
+    fn exchanged_fraction() {
+        let room = Room::new(40.0, 6.0, 0.0);
+
+        assert_eq!("0.00", format!("{:.2}", room.exchanged_fraction(0.0)));
+        assert_eq!("0.63", format!("{:.2}", room.exchanged_fraction(10.0)));
+        assert_eq!("1.00", format!("{:.2}", room.exchanged_fraction(120.0)));
+    }
+
+    macro_rules! try_new_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let ((volume, air_changes_per_hour, moisture_sources), expected) = $value;
+
+                    let room = Room::try_new(volume, air_changes_per_hour, moisture_sources);
+
+                    assert_eq!(expected, room.map_err(|error| error.to_string()));
+                }
+             )*
+        }
+    }
+
+    try_new_tests! {
+        valid: ((40.0, 6.0, 100.0), Ok(Room::new(40.0, 6.0, 100.0))),
+        zero_volume: ((0.0, 6.0, 0.0), Err("volume must be at least 1, got 0".to_string())),
+        negative_volume: ((-10.0, 6.0, 0.0), Err("volume must be at least 1, got -10".to_string())),
+        no_air_changes: (
+            (40.0, 0.0, 0.0),
+            Err("air changes per hour must be at least 0.1, got 0".to_string())
+        ),
+        negative_moisture_sources: (
+            (40.0, 6.0, -50.0),
+            Err("moisture sources must be at least 0, got -50".to_string())
+        ),
+        nan_volume: (
+            (f64::NAN, 6.0, 0.0),
+            Err("volume must be a number of at least 1".to_string())
+        ),
+    }
+
+    #[test]
+    #[should_panic(expected = "volume must be at least 1, got 0")]
+    fn new_panics() {
+        Room::new(0.0, 6.0, 0.0);
+    }
+}
@@ -0,0 +1,518 @@
+use alloc::boxed::Box;
+
+use crate::psychrometrics::{
+    absolute_humidity_at, humidity_ratio, saturation_vapour_pressure_at, vapour_pressure,
+};
+
+use super::{
+    comfort::{comfort_level, ComfortLevel},
+    measurement::Measurement,
+    plausibility::PlausibilityCheck,
+    policy::{delta_verdict, DecisionPolicy},
+    temperature::Temperature,
+    uncertainty::{Confidence, Estimate, SensorTolerance},
+    verdict::{Reason, Verdict},
+    OpenWindowResult,
+};
+
+/// Creates a strategy with its default settings.
+pub type StrategyFactory = fn() -> Box<dyn VentilationStrategy>;
+
+/// Strategies available via [strategy_by_name] - their names together with
+/// functions creating them.
+pub const STRATEGIES: [(&str, StrategyFactory); 4] = [
+    ("dew_point", boxed::<DecisionPolicy>),
+    ("absolute_humidity", boxed::<AbsoluteHumidityStrategy>),
+    ("comfort", boxed::<ComfortStrategy>),
+    ("energy", boxed::<EnergyStrategy>),
+];
+
+/// Name of the strategy used unless another one is picked - [DecisionPolicy]
+/// with its default settings, as used by [super::open_window].
+pub const DEFAULT_STRATEGY: &str = STRATEGIES[0].0;
+
+/// Names of strategies available via [strategy_by_name].
+pub const STRATEGY_NAMES: [&str; STRATEGIES.len()] = strategy_names();
+
+/// Holds information beside indoor and outdoor measurements, which a strategy
+/// may take into account.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StrategyContext {
+    /// Previously returned "open window" verdict, if any.
+    pub previous_open_window: Option<bool>,
+    /// Previous reading of the indoor sensor, checked for implausible jumps.
+    pub previous_indoor_measurement: Option<Measurement>,
+    /// Previous reading of the outdoor sensor, checked for implausible jumps.
+    pub previous_outdoor_measurement: Option<Measurement>,
+    /// Dew point (°C) reported by the indoor sensor itself, if it provides one.
+    pub reported_indoor_dew_point: Option<f64>,
+    /// Dew point (°C) reported by the outdoor sensor or a weather feed, if any.
+    pub reported_outdoor_dew_point: Option<f64>,
+}
+
+/// An algorithm answering a question whether one should open windows in order
+/// to decrease indoor humidity.
+pub trait VentilationStrategy {
+    /// Answers a question whether one should open windows, following this strategy.
+    fn evaluate(
+        &self,
+        indoor_measurement: &Measurement,
+        outdoor_measurement: &Measurement,
+        context: &StrategyContext,
+    ) -> OpenWindowResult;
+}
+
+/// Returns a strategy with its default settings, given its name (one of
+/// [STRATEGY_NAMES]), or `None` if there is no such strategy.
+pub fn strategy_by_name(name: &str) -> Option<Box<dyn VentilationStrategy>> {
+    STRATEGIES
+        .iter()
+        .find(|(strategy_name, _)| *strategy_name == name)
+        .map(|(_, strategy)| strategy())
+}
+
+fn boxed<S: VentilationStrategy + Default + 'static>() -> Box<dyn VentilationStrategy> {
+    Box::new(S::default())
+}
+
+const fn strategy_names() -> [&'static str; STRATEGIES.len()] {
+    let mut names = [""; STRATEGIES.len()];
+    let mut index = 0;
+
+    while index < STRATEGIES.len() {
+        names[index] = STRATEGIES[index].0;
+        index += 1;
+    }
+
+    names
+}
+
+/// Compares indoor and outdoor dew points.
+impl VentilationStrategy for DecisionPolicy {
+    fn evaluate(
+        &self,
+        indoor_measurement: &Measurement,
+        outdoor_measurement: &Measurement,
+        context: &StrategyContext,
+    ) -> OpenWindowResult {
+        self.evaluate_in_context(indoor_measurement, outdoor_measurement, context)
+    }
+}
+
+/// Compares indoor and outdoor absolute humidity (g/m³), both evaluated at
+/// indoor temperature - the mass of water vapour a cubic metre of exchanged air
+/// carries away once outdoor air has warmed up indoors.
+///
+/// At the same temperature absolute humidity is proportional to vapour
+/// pressure, so the verdict follows the dew points just like [DecisionPolicy]
+/// does. The strategy differs in the threshold, which is expressed as a mass
+/// of water (g/m³) instead of a temperature difference.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AbsoluteHumidityStrategy {
+    /// Minimum difference (g/m³) by which indoor absolute humidity has to
+    /// exceed outdoor absolute humidity in order to open windows.
+    pub min_absolute_humidity_delta: f64,
+    /// Width (g/m³) of a hysteresis band applied to a previously open window,
+    /// see [DecisionPolicy::hysteresis].
+    pub hysteresis: Option<f64>,
+    /// Check lowering confidence of the verdict for implausible readings.
+    pub plausibility_check: PlausibilityCheck,
+}
+
+impl VentilationStrategy for AbsoluteHumidityStrategy {
+    fn evaluate(
+        &self,
+        indoor_measurement: &Measurement,
+        outdoor_measurement: &Measurement,
+        context: &StrategyContext,
+    ) -> OpenWindowResult {
+        let indoor_dew_point = indoor_measurement.calculate_dew_point_estimate();
+        let outdoor_dew_point = outdoor_measurement.calculate_dew_point_estimate();
+
+        let indoor_absolute_humidity = absolute_humidity_estimate(
+            vapour_pressure(indoor_measurement),
+            &indoor_dew_point,
+            indoor_measurement,
+        );
+        let outdoor_absolute_humidity = absolute_humidity_estimate(
+            vapour_pressure(outdoor_measurement),
+            &outdoor_dew_point,
+            indoor_measurement,
+        );
+
+        let confidence = if self.plausibility_check.any_implausible(
+            indoor_measurement,
+            outdoor_measurement,
+            context,
+        ) {
+            Confidence::Implausible
+        } else if indoor_absolute_humidity.overlaps(&outdoor_absolute_humidity) {
+            Confidence::WithinSensorError
+        } else {
+            Confidence::Certain
+        };
+
+        let (verdict, mut reasons) = delta_verdict(
+            indoor_absolute_humidity.value - outdoor_absolute_humidity.value,
+            self.min_absolute_humidity_delta,
+            self.hysteresis,
+            context.previous_open_window,
+        );
+
+        if indoor_dew_point.value >= outdoor_measurement.temperature.value() {
+            reasons.push(Reason::CondensationRisk);
+        }
+
+        OpenWindowResult {
+            indoor_dew_point: indoor_dew_point.value,
+            outdoor_dew_point: outdoor_dew_point.value,
+            open_window: verdict == Verdict::Open,
+            verdict,
+            reasons,
+            confidence,
+        }
+    }
+}
+
+/// Estimates absolute humidity (g/m³) of air with provided vapour pressure
+/// (hPa), warmed up or cooled down to indoor temperature. Vapour pressure at
+/// the interval bounds is recovered from the dew point estimate, as it is the
+/// saturation vapour pressure at the dew point. Higher temperature means
+/// thinner air, so the indoor temperature tolerance widens the interval as well.
+fn absolute_humidity_estimate(
+    vapour_pressure: f64,
+    dew_point: &Estimate,
+    indoor_measurement: &Measurement,
+) -> Estimate {
+    let temperature = indoor_measurement.temperature.value();
+    let temperature_tolerance = indoor_measurement
+        .tolerance
+        .as_ref()
+        .map_or(0.0, SensorTolerance::temperature);
+    let absolute_humidity = |dew_point: f64, temperature: f64| {
+        absolute_humidity_at(saturation_vapour_pressure_at(dew_point), temperature)
+    };
+
+    Estimate {
+        value: absolute_humidity_at(vapour_pressure, temperature),
+        min: absolute_humidity(dew_point.min, temperature + temperature_tolerance),
+        max: absolute_humidity(dew_point.max, temperature - temperature_tolerance),
+    }
+}
+
+/// Compares indoor and outdoor dew points, but keeps windows closed if outdoor
+/// air is uncomfortably cold, or uncomfortably hot and humid (humidex of 30
+/// or more).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComfortStrategy {
+    /// Windows stay closed if outdoor temperature is below this limit.
+    pub min_outdoor_temperature: Temperature,
+}
+
+impl Default for ComfortStrategy {
+    fn default() -> Self {
+        Self {
+            min_outdoor_temperature: Temperature::new(10.0),
+        }
+    }
+}
+
+impl VentilationStrategy for ComfortStrategy {
+    fn evaluate(
+        &self,
+        indoor_measurement: &Measurement,
+        outdoor_measurement: &Measurement,
+        context: &StrategyContext,
+    ) -> OpenWindowResult {
+        let policy =
+            DecisionPolicy::default().min_outdoor_temperature(self.min_outdoor_temperature.clone());
+        let mut result =
+            policy.evaluate_in_context(indoor_measurement, outdoor_measurement, context);
+
+        if result.verdict == Verdict::Open
+            && comfort_level(outdoor_measurement) != ComfortLevel::Comfortable
+        {
+            override_verdict(&mut result, Verdict::Close, Reason::TooHotOutside);
+        }
+
+        result
+    }
+}
+
+/// Compares indoor and outdoor dew points, but keeps windows closed if the
+/// moisture removed by airing does not justify the heat lost.
+///
+/// Effectiveness is measured as a humidity ratio difference (g/kg) per degree
+/// of temperature difference (°C) between indoor and outdoor air. Airing is
+/// always worthwhile if outdoor air is warmer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnergyStrategy {
+    /// Policy deciding whether airing decreases indoor humidity at all.
+    pub policy: DecisionPolicy,
+    /// Minimum humidity ratio difference (g/kg) per degree of temperature
+    /// difference (°C).
+    pub min_moisture_per_degree: f64,
+}
+
+impl Default for EnergyStrategy {
+    fn default() -> Self {
+        Self {
+            policy: DecisionPolicy::default(),
+            min_moisture_per_degree: 0.2,
+        }
+    }
+}
+
+impl VentilationStrategy for EnergyStrategy {
+    fn evaluate(
+        &self,
+        indoor_measurement: &Measurement,
+        outdoor_measurement: &Measurement,
+        context: &StrategyContext,
+    ) -> OpenWindowResult {
+        let mut result =
+            self.policy
+                .evaluate_in_context(indoor_measurement, outdoor_measurement, context);
+
+        let temperature_delta =
+            indoor_measurement.temperature.value() - outdoor_measurement.temperature.value();
+
+        if result.verdict == Verdict::Open && temperature_delta > 0.0 {
+            // Humidity ratio is undefined for boiling air, which is never
+            // worth the heat loss check.
+            if let (Some(indoor_ratio), Some(outdoor_ratio)) = (
+                humidity_ratio(indoor_measurement),
+                humidity_ratio(outdoor_measurement),
+            ) {
+                let humidity_ratio_delta = indoor_ratio - outdoor_ratio;
+
+                if humidity_ratio_delta / temperature_delta < self.min_moisture_per_degree {
+                    override_verdict(&mut result, Verdict::Neutral, Reason::HeatLossTooHigh);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Replaces the verdict, adding a reason before [Reason::CondensationRisk],
+/// which by convention comes last.
+fn override_verdict(result: &mut OpenWindowResult, verdict: Verdict, reason: Reason) {
+    let index = result
+        .reasons
+        .iter()
+        .position(|reason| *reason == Reason::CondensationRisk)
+        .unwrap_or(result.reasons.len());
+
+    result.reasons.insert(index, reason);
+    result.verdict = verdict;
+    result.open_window = verdict == Verdict::Open;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement,
+        relative_humidity::RelativeHumidity,
+        temperature::Temperature,
+        uncertainty::{Confidence, SensorTolerance},
+        verdict::{Reason, Verdict},
+    };
+
+    use super::{
+        strategy_by_name, AbsoluteHumidityStrategy, StrategyContext, VentilationStrategy,
+        STRATEGY_NAMES,
+    };
+
+    macro_rules! strategy_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (strategy_name, (outdoor_temperature, outdoor_humidity), expected) = $value;
+
+                    // Indoor dew point: 8.82 °C.
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+
+                    let outdoor_measurement = Measurement::new(
+                        Temperature::new(outdoor_temperature),
+                        RelativeHumidity::new(outdoor_humidity),
+                    );
+
+                    let strategy = strategy_by_name(strategy_name).unwrap();
+                    let result = strategy.evaluate(
+                        &indoor_measurement,
+                        &outdoor_measurement,
+                        &StrategyContext::default(),
+                    );
+
+                    assert_eq!(expected, (result.verdict, result.reasons));
+                    assert_eq!(result.verdict == Verdict::Open, result.open_window);
+                }
+             )*
+        }
+    }
+
+    strategy_tests! {
+        dew_point: ("dew_point", (10.0, 88), (Verdict::Open, vec![Reason::OutdoorDrier])),
+        absolute_humidity_drier: (
+            "absolute_humidity",
+            (10.0, 88),
+            (Verdict::Open, vec![Reason::OutdoorDrier])
+        ),
+        // Outdoor dew point is lower (8.55 °C). A cubic metre of cold outdoor
+        // air holds more water vapour (8.54 g/m³ vs. 8.43 g/m³), but only
+        // 8.28 g/m³ once warmed up to indoor temperature.
+        dew_point_cold_saturated_outside: (
+            "dew_point",
+            (9.0, 97),
+            (Verdict::Open, vec![Reason::OutdoorDrier])
+        ),
+        absolute_humidity_cold_saturated_outside: (
+            "absolute_humidity",
+            (9.0, 97),
+            (Verdict::Open, vec![Reason::OutdoorDrier])
+        ),
+        absolute_humidity_wetter: (
+            "absolute_humidity",
+            (25.0, 40),
+            (Verdict::Close, vec![Reason::OutdoorWetter])
+        ),
+        comfort_too_cold: (
+            "comfort",
+            (0.0, 91),
+            (
+                Verdict::Close,
+                vec![Reason::OutdoorDrier, Reason::TooColdOutside, Reason::CondensationRisk]
+            )
+        ),
+        comfort_too_hot: (
+            "comfort",
+            (34.0, 20),
+            (Verdict::Close, vec![Reason::OutdoorDrier, Reason::TooHotOutside])
+        ),
+        comfort_open: ("comfort", (15.0, 60), (Verdict::Open, vec![Reason::OutdoorDrier])),
+        energy_worthwhile: (
+            "energy",
+            (0.0, 60),
+            (Verdict::Open, vec![Reason::OutdoorDrier, Reason::CondensationRisk])
+        ),
+        energy_heat_loss_too_high: (
+            "energy",
+            (10.0, 88),
+            (Verdict::Neutral, vec![Reason::OutdoorDrier, Reason::HeatLossTooHigh])
+        ),
+        energy_warmer_outside: ("energy", (22.0, 30), (Verdict::Open, vec![Reason::OutdoorDrier])),
+    }
+
+    #[test]
+    fn all_strategies_by_name() {
+        for name in STRATEGY_NAMES {
+            assert!(strategy_by_name(name).is_some());
+        }
+    }
+
+    #[test]
+    fn unknown_strategy() {
+        assert!(strategy_by_name("unknown").is_none());
+    }
+
+    macro_rules! absolute_humidity_hysteresis_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (hysteresis, previous_open_window, expected) = $value;
+
+                    // Indoor: 8.43 g/m³, outdoor at indoor temperature: 8.67 g/m³.
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+                    let outdoor_measurement =
+                        Measurement::new(Temperature::new(10.0), RelativeHumidity::new(95));
+
+                    let strategy = AbsoluteHumidityStrategy {
+                        hysteresis,
+                        ..Default::default()
+                    };
+                    let result = strategy.evaluate(
+                        &indoor_measurement,
+                        &outdoor_measurement,
+                        &StrategyContext {
+                            previous_open_window,
+                            ..Default::default()
+                        },
+                    );
+
+                    assert_eq!(expected, (result.verdict, result.reasons));
+                }
+             )*
+        }
+    }
+
+    absolute_humidity_hysteresis_tests! {
+        absolute_humidity_hysteresis_keeps_open: (
+            Some(0.5),
+            Some(true),
+            (Verdict::Open, vec![Reason::OutdoorWetter, Reason::WithinHysteresis])
+        ),
+        absolute_humidity_hysteresis_keeps_closed: (
+            Some(0.5),
+            Some(false),
+            (Verdict::Close, vec![Reason::OutdoorWetter])
+        ),
+        absolute_humidity_without_hysteresis: (
+            None,
+            Some(true),
+            (Verdict::Close, vec![Reason::OutdoorWetter])
+        ),
+    }
+
+    macro_rules! absolute_humidity_confidence_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (indoor, outdoor, expected) = $value;
+                    let (indoor_temperature, indoor_humidity) = indoor;
+                    let (outdoor_temperature, outdoor_humidity) = outdoor;
+
+                    let tolerance = SensorTolerance::new(0.5, 3.0);
+                    let indoor_measurement = Measurement::new(
+                        Temperature::new(indoor_temperature),
+                        RelativeHumidity::new(indoor_humidity),
+                    )
+                    .with_tolerance(tolerance.clone());
+                    let outdoor_measurement = Measurement::new(
+                        Temperature::new(outdoor_temperature),
+                        RelativeHumidity::new(outdoor_humidity),
+                    )
+                    .with_tolerance(tolerance);
+
+                    let result = AbsoluteHumidityStrategy::default().evaluate(
+                        &indoor_measurement,
+                        &outdoor_measurement,
+                        &StrategyContext::default(),
+                    );
+
+                    assert_eq!(expected, (result.verdict, result.confidence));
+                }
+             )*
+        }
+    }
+
+    absolute_humidity_confidence_tests! {
+        absolute_humidity_winter: (
+            (20.0, 40),
+            (6.0, 97),
+            (Verdict::Open, Confidence::WithinSensorError)
+        ),
+        absolute_humidity_certain: ((20.0, 60), (0.0, 80), (Verdict::Open, Confidence::Certain)),
+        absolute_humidity_certainly_wetter: (
+            (18.0, 40),
+            (25.0, 80),
+            (Verdict::Close, Confidence::Certain)
+        ),
+    }
+}
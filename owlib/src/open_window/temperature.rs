@@ -1,26 +1,106 @@
-use core::fmt;
-use std::error::Error;
+use core::{fmt, str::FromStr};
 
-const VALIDATION_ERROR: &str = "temperature must be a decimal value between -100.0 and 100.0";
-/// Minimum value of temperature
+use crate::Error;
+
+use super::limits::Limits;
+
+/// Minimum value of temperature accepted by default (see [Limits])
 pub const MIN_TEMP: f64 = -100.0;
-/// Maximum value of temperature
+/// Maximum value of temperature accepted by default (see [Limits])
 pub const MAX_TEMP: f64 = 100.0;
+/// Lowest temperature [Limits] can be widened to. Magnus type formulas have a
+/// singularity at -237.7 °C
+/// ([DewPointFormula::MagnusTetens](super::dew_point::DewPointFormula::MagnusTetens))
+/// or below, beyond which calculated values are meaningless.
+pub const LOWEST_TEMP_LIMIT: f64 = -200.0;
+
+const ZERO_CELSIUS_IN_KELVIN: f64 = 273.15;
 
-/// An error returned by [Temperature::try_new] if provided value is invalid.
+/// An error returned when parsing a [Temperature] from a string fails.
 #[derive(Debug)]
-pub struct TemperatureInvalid(pub &'static str);
+pub enum ParseTemperatureError {
+    /// The string does not contain a decimal number, optionally followed by a unit.
+    InvalidNumber,
+    /// The number was parsed, but it is not a valid temperature value.
+    Invalid(Error),
+}
 
-impl Error for TemperatureInvalid {}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTemperatureError {}
 
-impl fmt::Display for TemperatureInvalid {
+impl fmt::Display for ParseTemperatureError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{VALIDATION_ERROR}")
+        match self {
+            Self::InvalidNumber => write!(
+                f,
+                "temperature must be a decimal value optionally followed by a unit (C, F or K)"
+            ),
+            Self::Invalid(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// Unit in which a temperature value is expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TemperatureUnit {
+    /// Celsius degrees (°C).
+    #[default]
+    Celsius,
+    /// Fahrenheit degrees (°F).
+    Fahrenheit,
+    /// Kelvins (K).
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    /// Returns a symbol of the unit.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+            Self::Kelvin => "K",
+        }
+    }
+
+    fn convert_to_celsius(self, value: f64) -> f64 {
+        match self {
+            Self::Celsius => value,
+            Self::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            Self::Kelvin => value - ZERO_CELSIUS_IN_KELVIN,
+        }
+    }
+
+    fn convert_from_celsius(self, value: f64) -> f64 {
+        match self {
+            Self::Celsius => value,
+            Self::Fahrenheit => value * 9.0 / 5.0 + 32.0,
+            Self::Kelvin => value + ZERO_CELSIUS_IN_KELVIN,
+        }
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
     }
 }
 
 /// Holds a temperature value expressed in Celcius degrees (°C).
+///
+/// Values expressed in other units can be converted with [Temperature::from_unit]
+/// (or [Temperature::from_fahrenheit] and [Temperature::from_kelvin]). The range
+/// constants [MIN_TEMP] and [MAX_TEMP] are always expressed in Celsius degrees.
+///
+/// Accepted values depend on [Limits] - functions without a `limits` parameter,
+/// [FromStr] and deserialization use the default ones. Deserialize an `f64`
+/// and pass it to [Temperature::try_new_with_limits] in order to accept
+/// a different range.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "f64", into = "f64")
+)]
 pub struct Temperature {
     value: f64,
 }
@@ -30,33 +110,239 @@ impl Temperature {
     ///
     /// # Panics
     ///
-    /// Panics if provided value does not fall within following range `(-100.0..=100.0)`.
+    /// Panics if provided value does not fall within the default [Limits]
+    /// ([MIN_TEMP]..=[MAX_TEMP]). Use [Temperature::try_new_with_limits] for
+    /// a different range.
     pub fn new(value: f64) -> Self {
-        if !Self::valid(value) {
-            panic!("{}", VALIDATION_ERROR);
+        match Self::try_new(value) {
+            Ok(temperature) => temperature,
+            Err(error) => panic!("{error}"),
         }
-
-        Self { value }
     }
 
     /// Creates new `Temperature` struct.
     ///
     /// As opposed to [Temperature::new] function it does not panic, but returns
-    /// a [TemperatureInvalid] error instead.
-    pub fn try_new(value: f64) -> Result<Self, TemperatureInvalid> {
-        if !Self::valid(value) {
-            return Err(TemperatureInvalid(VALIDATION_ERROR));
-        }
+    /// an [Error] instead.
+    pub fn try_new(value: f64) -> Result<Self, Error> {
+        Self::try_new_with_limits(value, &Limits::default())
+    }
+
+    /// Creates new `Temperature` struct, accepting values within provided
+    /// limits instead of the default ones. The limits can not be widened below
+    /// [LOWEST_TEMP_LIMIT].
+    pub fn try_new_with_limits(value: f64, limits: &Limits) -> Result<Self, Error> {
+        let value = limits.validate_temperature(value)?;
+
+        Ok(Self { value })
+    }
+
+    /// Creates new `Temperature` struct from a value expressed in provided unit.
+    ///
+    /// Returns an [Error] if the value, converted to Celsius degrees, does not
+    /// fall within the default [Limits].
+    pub fn from_unit(value: f64, unit: TemperatureUnit) -> Result<Self, Error> {
+        Self::from_unit_with_limits(value, unit, &Limits::default())
+    }
+
+    /// Creates new `Temperature` struct from a value expressed in provided unit,
+    /// accepting values within provided limits (expressed in Celsius degrees)
+    /// instead of the default ones.
+    pub fn from_unit_with_limits(
+        value: f64,
+        unit: TemperatureUnit,
+        limits: &Limits,
+    ) -> Result<Self, Error> {
+        Self::try_new_with_limits(unit.convert_to_celsius(value), limits)
+    }
+
+    /// Parses a temperature the same way as [FromStr] does, accepting values
+    /// within provided limits instead of the default ones.
+    pub fn parse_with_limits(s: &str, limits: &Limits) -> Result<Self, ParseTemperatureError> {
+        let s = s.trim();
+
+        let (number, unit) = match s.char_indices().last() {
+            Some((index, 'C' | 'c')) => (&s[..index], TemperatureUnit::Celsius),
+            Some((index, 'F' | 'f')) => (&s[..index], TemperatureUnit::Fahrenheit),
+            Some((index, 'K' | 'k')) => (&s[..index], TemperatureUnit::Kelvin),
+            _ => (s, TemperatureUnit::Celsius),
+        };
 
-        Ok(Self::new(value))
+        let number = number.trim_end();
+        let number = number.strip_suffix('°').unwrap_or(number).trim_end();
+
+        let value = number
+            .parse::<f64>()
+            .map_err(|_| ParseTemperatureError::InvalidNumber)?;
+
+        Self::from_unit_with_limits(value, unit, limits).map_err(ParseTemperatureError::Invalid)
+    }
+
+    /// Creates new `Temperature` struct from a value expressed in Fahrenheit degrees.
+    pub fn from_fahrenheit(value: f64) -> Result<Self, Error> {
+        Self::from_unit(value, TemperatureUnit::Fahrenheit)
+    }
+
+    /// Creates new `Temperature` struct from a value expressed in kelvins.
+    pub fn from_kelvin(value: f64) -> Result<Self, Error> {
+        Self::from_unit(value, TemperatureUnit::Kelvin)
     }
 
-    /// Returns a temperature value.
+    /// Returns a temperature value (expressed in Celsius degrees).
     pub fn value(&self) -> f64 {
         self.value
     }
 
-    fn valid(value: f64) -> bool {
-        (MIN_TEMP..=MAX_TEMP).contains(&value)
+    /// Returns a temperature value expressed in provided unit.
+    pub fn as_unit(&self, unit: TemperatureUnit) -> f64 {
+        unit.convert_from_celsius(self.value)
+    }
+
+    /// Returns a temperature value expressed in Fahrenheit degrees.
+    pub fn as_fahrenheit(&self) -> f64 {
+        self.as_unit(TemperatureUnit::Fahrenheit)
+    }
+
+    /// Returns a temperature value expressed in kelvins.
+    pub fn as_kelvin(&self) -> f64 {
+        self.as_unit(TemperatureUnit::Kelvin)
+    }
+}
+
+impl TryFrom<f64> for Temperature {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<Temperature> for f64 {
+    fn from(temperature: Temperature) -> Self {
+        temperature.value
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, "{}", TemperatureUnit::Celsius)
+    }
+}
+
+impl FromStr for Temperature {
+    type Err = ParseTemperatureError;
+
+    /// Parses a temperature from a decimal value optionally followed by a unit,
+    /// e.g. `"18"`, `"18°C"`, `"64.4F"` or `"291.15 K"`. Values without a unit are
+    /// treated as Celsius degrees. The value has to fall within the default
+    /// [Limits], see [Temperature::parse_with_limits].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_limits(s, &Limits::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Field};
+
+    use crate::open_window::limits::Limits;
+
+    use super::{Temperature, TemperatureUnit, MAX_TEMP, MIN_TEMP};
+
+    macro_rules! parse_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (input, expected) = $value;
+
+                    let temperature = input.parse::<Temperature>().unwrap();
+
+                    assert_eq!(expected, format!("{temperature:.2}"));
+                }
+             )*
+        }
+    }
+
+    parse_tests! {
+        parse_without_unit: ("18", "18.00°C"),
+        parse_celsius: ("-5.5°C", "-5.50°C"),
+        parse_fahrenheit: ("64.4F", "18.00°C"),
+        parse_kelvin: ("291.15 K", "18.00°C"),
+        parse_lowercase: ("32f", "0.00°C"),
+    }
+
+    #[test]
+    fn parse_invalid_number() {
+        assert!("warm".parse::<Temperature>().is_err());
+        assert!("F".parse::<Temperature>().is_err());
+    }
+
+    #[test]
+    fn parse_out_of_range() {
+        assert!("300F".parse::<Temperature>().is_err());
+        assert!("64.4".parse::<Temperature>().is_ok());
+    }
+
+    #[test]
+    fn parse_with_limits() {
+        let limits = Limits::default().temperature(-150.0, 150.0);
+
+        let temperature = Temperature::parse_with_limits("-238F", &limits).unwrap();
+
+        assert_eq!("-150.00", format!("{:.2}", temperature.value()));
+        assert!("-238F".parse::<Temperature>().is_err());
+        assert!(Temperature::parse_with_limits("-160", &limits).is_err());
+    }
+
+    #[test]
+    fn from_unit_with_limits() {
+        let limits = Limits::default().temperature(-150.0, 150.0);
+
+        let temperature =
+            Temperature::from_unit_with_limits(123.15, TemperatureUnit::Kelvin, &limits).unwrap();
+
+        assert_eq!("-150.00", format!("{:.2}", temperature.value()));
+        assert!(Temperature::from_kelvin(123.15).is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        let temperature = Temperature::from_fahrenheit(64.4).unwrap();
+
+        assert_eq!("18.00", format!("{:.2}", temperature.value()));
+        assert_eq!("64.40", format!("{:.2}", temperature.as_fahrenheit()));
+        assert_eq!("291.15", format!("{:.2}", temperature.as_kelvin()));
+        assert_eq!(
+            "18.00",
+            format!("{:.2}", temperature.as_unit(TemperatureUnit::Celsius))
+        );
+    }
+
+    #[test]
+    fn from_kelvin_below_range() {
+        assert!(Temperature::from_kelvin(0.0).is_err());
+    }
+
+    #[test]
+    fn try_new_not_finite() {
+        assert_eq!(
+            Err(Error::NaN {
+                field: Field::Temperature,
+                min: MIN_TEMP,
+                max: Some(MAX_TEMP)
+            }),
+            Temperature::try_new(f64::NAN)
+        );
+        assert_eq!(
+            Err(Error::Infinite {
+                field: Field::Temperature,
+                value: f64::INFINITY,
+                min: MIN_TEMP,
+                max: Some(MAX_TEMP)
+            }),
+            Temperature::try_new(f64::INFINITY)
+        );
     }
 }
@@ -0,0 +1,353 @@
+use core::fmt;
+
+use crate::{Error, Field};
+
+use super::{
+    dew_point::DewPointFormula,
+    relative_humidity::{MAX_HUMIDITY, MIN_HUMIDITY},
+    temperature::LOWEST_TEMP_LIMIT,
+};
+
+/// Maximum temperature tolerance (±°C) of a sensor.
+pub const MAX_TEMPERATURE_TOLERANCE: f64 = 10.0;
+/// Maximum relative humidity tolerance (±%) of a sensor.
+pub const MAX_RELATIVE_HUMIDITY_TOLERANCE: f64 = 20.0;
+
+/// Holds an accuracy of a sensor which performed a `Measurement`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "SensorToleranceData", into = "SensorToleranceData")
+)]
+pub struct SensorTolerance {
+    temperature: f64,
+    relative_humidity: f64,
+}
+
+/// Serialized form of [SensorTolerance], validated by [SensorTolerance::try_new]
+/// when deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SensorToleranceData {
+    temperature: f64,
+    relative_humidity: f64,
+}
+
+impl SensorTolerance {
+    /// Creates new `SensorTolerance` struct, given temperature accuracy (±°C)
+    /// and relative humidity accuracy (±%).
+    ///
+    /// # Panics
+    ///
+    /// Panics if temperature tolerance does not fall within
+    /// `0.0..=`[MAX_TEMPERATURE_TOLERANCE] or relative humidity tolerance does
+    /// not fall within `0.0..=`[MAX_RELATIVE_HUMIDITY_TOLERANCE].
+    pub fn new(temperature: f64, relative_humidity: f64) -> Self {
+        match Self::try_new(temperature, relative_humidity) {
+            Ok(tolerance) => tolerance,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Creates new `SensorTolerance` struct.
+    ///
+    /// As opposed to [SensorTolerance::new] function it does not panic, but
+    /// returns an [Error] instead.
+    pub fn try_new(temperature: f64, relative_humidity: f64) -> Result<Self, Error> {
+        Ok(Self {
+            temperature: Error::validate(
+                Field::TemperatureTolerance,
+                temperature,
+                0.0,
+                MAX_TEMPERATURE_TOLERANCE,
+            )?,
+            relative_humidity: Error::validate(
+                Field::RelativeHumidityTolerance,
+                relative_humidity,
+                0.0,
+                MAX_RELATIVE_HUMIDITY_TOLERANCE,
+            )?,
+        })
+    }
+
+    /// Returns temperature accuracy expressed in Celsius degrees (±°C).
+    pub fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    /// Returns relative humidity accuracy expressed in percentage points (±%).
+    pub fn relative_humidity(&self) -> f64 {
+        self.relative_humidity
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SensorToleranceData> for SensorTolerance {
+    type Error = Error;
+
+    fn try_from(data: SensorToleranceData) -> Result<Self, Self::Error> {
+        Self::try_new(data.temperature, data.relative_humidity)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SensorTolerance> for SensorToleranceData {
+    fn from(tolerance: SensorTolerance) -> Self {
+        Self {
+            temperature: tolerance.temperature,
+            relative_humidity: tolerance.relative_humidity,
+        }
+    }
+}
+
+/// Holds a calculated value together with an interval it falls into, given
+/// sensor tolerances.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    /// Value calculated from the measured values.
+    pub value: f64,
+    /// Lowest possible value.
+    pub min: f64,
+    /// Highest possible value.
+    pub max: f64,
+}
+
+impl Estimate {
+    /// Creates an `Estimate` of an exactly known value.
+    pub(crate) fn exact(value: f64) -> Self {
+        Self {
+            value,
+            min: value,
+            max: value,
+        }
+    }
+
+    /// Checks whether intervals of both estimates overlap.
+    pub fn overlaps(&self, other: &Estimate) -> bool {
+        self.min <= other.max && other.min <= self.max
+    }
+}
+
+/// Describes how much the "open window" verdict can be trusted, given sensor
+/// tolerances and plausibility of the readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Confidence {
+    /// Dew point difference is larger than sensor error.
+    Certain,
+    /// Dew point difference is within sensor error - the verdict may be wrong.
+    WithinSensorError,
+    /// Some of the readings look implausible (see
+    /// [PlausibilityCheck](super::plausibility::PlausibilityCheck)) - the
+    /// verdict can not be trusted.
+    Implausible,
+}
+
+impl Confidence {
+    /// Returns a machine-readable code of the confidence.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Certain => "certain",
+            Self::WithinSensorError => "within_sensor_error",
+            Self::Implausible => "implausible",
+        }
+    }
+
+    /// Returns a human-readable message describing the confidence.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::Certain => "Dew point difference exceeds sensor error.",
+            Self::WithinSensorError => "Dew point difference is within sensor error.",
+            Self::Implausible => "Sensor readings look implausible.",
+        }
+    }
+}
+
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+/// Calculates a dew point estimate. Dew point grows with both temperature and
+/// relative humidity, so the interval bounds are calculated from the lowest and
+/// highest possible readings.
+///
+/// The measured values are used as they are, only the bounds shifted by the
+/// tolerance are clamped - relative humidity to [MIN_HUMIDITY]..=[MAX_HUMIDITY]
+/// (or the measured value, if lower) and temperature to [LOWEST_TEMP_LIMIT],
+/// above the singularity of the formula. Therefore the lowest value of the
+/// formula for completely dry air does not leak into the bounds.
+pub(crate) fn dew_point_estimate(
+    temperature: f64,
+    relative_humidity: f64,
+    tolerance: &SensorTolerance,
+) -> Estimate {
+    let dew_point = |temperature: f64, relative_humidity: f64| {
+        let formula = DewPointFormula::default();
+
+        formula
+            .dew_point(relative_humidity / 100.0 * formula.saturation_vapour_pressure(temperature))
+    };
+
+    Estimate {
+        value: dew_point(temperature, relative_humidity),
+        min: dew_point(
+            (temperature - tolerance.temperature).max(LOWEST_TEMP_LIMIT),
+            (relative_humidity - tolerance.relative_humidity)
+                .max((MIN_HUMIDITY as f64).min(relative_humidity)),
+        ),
+        max: dew_point(
+            temperature + tolerance.temperature,
+            (relative_humidity + tolerance.relative_humidity).min(MAX_HUMIDITY as f64),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, open_window_result, relative_humidity::RelativeHumidity,
+        temperature::Temperature,
+    };
+
+    use super::{Confidence, SensorTolerance};
+
+    macro_rules! dew_point_estimate_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let ((temperature, relative_humidity, tolerance), expected) = $value;
+                    let measurement = Measurement::new(
+                        Temperature::new(temperature),
+                        RelativeHumidity::new(relative_humidity),
+                    )
+                    .with_tolerance(tolerance);
+
+                    let estimate = measurement.calculate_dew_point_estimate();
+
+                    assert_eq!(
+                        expected,
+                        (
+                            format!("{:.2}", estimate.value).as_str(),
+                            format!("{:.2}", estimate.min).as_str(),
+                            format!("{:.2}", estimate.max).as_str(),
+                        )
+                    );
+                }
+             )*
+        }
+    }
+
+    dew_point_estimate_tests! {
+        dew_point_estimate: ((18.0, 55, SensorTolerance::new(0.5, 3.0)), ("8.82", "7.53", "10.08")),
+        dew_point_estimate_exact: ((18.0, 55, SensorTolerance::default()), ("8.82", "8.82", "8.82")),
+        dew_point_estimate_clamped_to_valid_humidity: (
+            (18.0, 99, SensorTolerance::new(0.5, 3.0)),
+            ("17.84", "16.85", "18.50")
+        ),
+        dew_point_estimate_clamped_to_min_humidity: (
+            (18.0, 5, SensorTolerance::new(0.5, 20.0)),
+            ("-22.30", "-39.51", "-1.91")
+        ),
+    }
+
+    #[test]
+    fn dew_point_estimate_without_tolerance() {
+        let measurement = Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+
+        let estimate = measurement.calculate_dew_point_estimate();
+
+        assert_eq!(estimate.value, estimate.min);
+        assert_eq!(estimate.value, estimate.max);
+    }
+
+    macro_rules! confidence_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let ((indoor, outdoor), expected) = $value;
+                    let measurement = |(temperature, relative_humidity)| {
+                        Measurement::new(
+                            Temperature::new(temperature),
+                            RelativeHumidity::new(relative_humidity),
+                        )
+                        .with_tolerance(SensorTolerance::new(0.5, 3.0))
+                    };
+
+                    let result = open_window_result(&measurement(indoor), &measurement(outdoor));
+
+                    assert!(result.open_window);
+                    assert_eq!(expected, result.confidence);
+                }
+             )*
+        }
+    }
+
+    confidence_tests! {
+        within_sensor_error: (((18.0, 55), (10.0, 88)), Confidence::WithinSensorError),
+        certain: (((18.0, 55), (0.0, 91)), Confidence::Certain),
+    }
+
+    macro_rules! try_new_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let ((temperature, relative_humidity), expected) = $value;
+
+                    let tolerance = SensorTolerance::try_new(temperature, relative_humidity);
+
+                    assert_eq!(expected, tolerance.map_err(|error| error.to_string()));
+                }
+             )*
+        }
+    }
+
+    try_new_tests! {
+        valid: ((0.5, 3.0), Ok(SensorTolerance::new(0.5, 3.0))),
+        exact: ((0.0, 0.0), Ok(SensorTolerance::default())),
+        negative_temperature: (
+            (-1.0, 3.0),
+            Err("temperature tolerance must be at least 0, got -1".to_string())
+        ),
+        huge_relative_humidity: (
+            (0.5, 1000.0),
+            Err("relative humidity tolerance must be at most 20, got 1000".to_string())
+        ),
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        let tolerance: SensorTolerance =
+            serde_json::from_str(r#"{"temperature":0.5,"relative_humidity":3}"#).unwrap();
+
+        assert_eq!(SensorTolerance::new(0.5, 3.0), tolerance);
+        assert_eq!(
+            tolerance,
+            serde_json::from_value(serde_json::to_value(&tolerance).unwrap()).unwrap()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_invalid() {
+        let error = serde_json::from_str::<SensorTolerance>(
+            r#"{"temperature":-1,"relative_humidity":1e308}"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            "temperature tolerance must be at least 0, got -1",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use crate::math;
+use crate::psychrometrics::{
+    absolute_humidity_at, air_density, saturation_vapour_pressure, saturation_vapour_pressure_at,
+    vapour_pressure, AIR_SPECIFIC_HEAT, LATENT_HEAT_OF_VAPORISATION,
+};
+
+use super::{
+    measurement::Measurement, relative_humidity::RelativeHumidity, room::Room,
+    temperature::Temperature,
+};
+
+/// Number of kJ in one kWh.
+const KJ_PER_KWH: f64 = 3600.0;
+
+/// Indoor humidity level which ventilation should reach.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VentilationTarget {
+    /// Relative humidity at indoor temperature.
+    RelativeHumidity(RelativeHumidity),
+    /// Indoor dew point expressed in Celsius degrees (°C).
+    DewPoint(f64),
+}
+
+/// Predicts indoor relative humidity (%) after airing the room.
+///
+/// `exchanged_fraction` is a fraction (`0.0..=1.0`) of indoor air replaced by
+/// outdoor air while the window was open - `1.0` means the whole air mass was
+/// exchanged. The resulting air mixture keeps its water vapour content, while
+/// it is brought to `target_temperature` after the window is shut (e.g. heated
+/// back to the thermostat setpoint).
+///
+/// The result is capped at 100%, since any excess water vapour condenses.
+///
+/// # Example
+///
+/// ```
+/// use owlib::open_window::measurement::Measurement;
+/// use owlib::open_window::relative_humidity::RelativeHumidity;
+/// use owlib::open_window::temperature::Temperature;
+/// use owlib::open_window::ventilation::predict_relative_humidity;
+///
+/// let indoor_measurement = Measurement::new(Temperature::new(22.0), RelativeHumidity::new(65));
+///
+/// let outdoor_measurement = Measurement::new(Temperature::new(2.0), RelativeHumidity::new(80));
+///
+/// let relative_humidity = predict_relative_humidity(
+///     &indoor_measurement,
+///     &outdoor_measurement,
+///     &Temperature::new(20.0),
+///     1.0,
+/// );
+///
+/// assert!(relative_humidity < 25.0);
+/// ```
+pub fn predict_relative_humidity(
+    indoor_measurement: &Measurement,
+    outdoor_measurement: &Measurement,
+    target_temperature: &Temperature,
+    exchanged_fraction: f64,
+) -> f64 {
+    let exchanged_fraction = exchanged_fraction.clamp(0.0, 1.0);
+
+    let mixed_vapour_pressure = (1.0 - exchanged_fraction) * vapour_pressure(indoor_measurement)
+        + exchanged_fraction * vapour_pressure(outdoor_measurement);
+
+    (mixed_vapour_pressure / saturation_vapour_pressure(target_temperature) * 100.0).min(100.0)
+}
+
+/// Estimates how many minutes of ventilation are needed to bring indoor
+/// humidity down to provided target.
+///
+/// The estimate assumes that indoor temperature stays constant, incoming
+/// outdoor air is perfectly mixed with indoor air and the room's moisture
+/// sources keep releasing water vapour at a constant rate.
+///
+/// Returns `Some(0.0)` if indoor humidity is already at or below the target,
+/// and `None` if the target cannot be reached by ventilation - outdoor air is
+/// too humid, or the moisture sources outweigh the air exchange - or the dew
+/// point target is not a finite number.
+pub fn ventilation_duration(
+    room: &Room,
+    indoor_measurement: &Measurement,
+    outdoor_measurement: &Measurement,
+    target: &VentilationTarget,
+) -> Option<f64> {
+    let indoor_temperature = indoor_measurement.temperature.value();
+
+    let target_vapour_pressure = match target {
+        VentilationTarget::RelativeHumidity(relative_humidity) => {
+            relative_humidity.percent() / 100.0 * saturation_vapour_pressure_at(indoor_temperature)
+        }
+        VentilationTarget::DewPoint(dew_point) if dew_point.is_finite() => {
+            saturation_vapour_pressure_at(*dew_point)
+        }
+        VentilationTarget::DewPoint(_) => return None,
+    };
+
+    let indoor = absolute_humidity_at(vapour_pressure(indoor_measurement), indoor_temperature);
+    let outdoor = absolute_humidity_at(vapour_pressure(outdoor_measurement), indoor_temperature);
+    let target = absolute_humidity_at(target_vapour_pressure, indoor_temperature);
+
+    if indoor <= target {
+        return Some(0.0);
+    }
+
+    // Absolute humidity the room converges to while the window stays open.
+    let steady_state =
+        outdoor + room.moisture_sources() / (room.air_changes_per_hour() * room.volume());
+
+    if steady_state >= target {
+        return None;
+    }
+
+    let hours =
+        math::ln((indoor - steady_state) / (target - steady_state)) / room.air_changes_per_hour();
+
+    Some(hours * 60.0)
+}
+
+/// Holds water and energy balance of airing a room.
+///
+/// Negative values mean the opposite effect - the room gains water or heat.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VentilationEnergy {
+    /// Water vapour removed from the room expressed in grams (g).
+    pub water_removed: f64,
+    /// Heat needed to warm incoming outdoor air up to indoor temperature,
+    /// expressed in kWh.
+    pub sensible_energy: f64,
+    /// Heat carried away with the removed water vapour, expressed in kWh.
+    pub latent_energy: f64,
+}
+
+impl VentilationEnergy {
+    /// Returns the sum of sensible and latent energy (kWh).
+    pub fn total_energy(&self) -> f64 {
+        self.sensible_energy + self.latent_energy
+    }
+
+    /// Returns a cost of the total energy, given a price per kWh.
+    pub fn cost(&self, price_per_kwh: f64) -> f64 {
+        self.total_energy() * price_per_kwh
+    }
+}
+
+/// Estimates how much water and energy airing the room for provided number
+/// of minutes removes.
+///
+/// The estimate follows the same assumptions as [ventilation_duration] -
+/// indoor temperature is kept constant by heating (or cooling), incoming air
+/// is perfectly mixed with indoor air and moisture sources release water
+/// vapour at a constant rate.
+///
+/// Returns `None` if indoor vapour pressure is not below barometric pressure,
+/// as density of indoor air is not defined then.
+///
+/// # Example
+///
+/// ```
+/// use owlib::open_window::measurement::Measurement;
+/// use owlib::open_window::relative_humidity::RelativeHumidity;
+/// use owlib::open_window::room::Room;
+/// use owlib::open_window::temperature::Temperature;
+/// use owlib::open_window::ventilation::ventilation_energy;
+///
+/// let room = Room::new(50.0, 6.0, 0.0);
+///
+/// let indoor_measurement = Measurement::new(Temperature::new(20.0), RelativeHumidity::new(60));
+///
+/// let outdoor_measurement = Measurement::new(Temperature::new(-5.0), RelativeHumidity::new(80));
+///
+/// let energy =
+///     ventilation_energy(&room, &indoor_measurement, &outdoor_measurement, 10.0).unwrap();
+///
+/// println!(
+///     "Airing now removes {:.0} g of water and costs about {:.1} kWh.",
+///     energy.water_removed,
+///     energy.total_energy(),
+/// );
+/// ```
+pub fn ventilation_energy(
+    room: &Room,
+    indoor_measurement: &Measurement,
+    outdoor_measurement: &Measurement,
+    minutes: f64,
+) -> Option<VentilationEnergy> {
+    let indoor_temperature = indoor_measurement.temperature.value();
+    let minutes = minutes.max(0.0);
+
+    let indoor = absolute_humidity_at(vapour_pressure(indoor_measurement), indoor_temperature);
+    let outdoor = absolute_humidity_at(vapour_pressure(outdoor_measurement), indoor_temperature);
+
+    // Absolute humidity the room converges to while the window stays open.
+    let steady_state =
+        outdoor + room.moisture_sources() / (room.air_changes_per_hour() * room.volume());
+    let water_removed = room.volume() * (indoor - steady_state) * room.exchanged_fraction(minutes);
+
+    let exchanged_air_mass =
+        air_density(indoor_measurement)? * room.volume() * room.air_changes_per_hour() * minutes
+            / 60.0;
+    let sensible_energy = exchanged_air_mass
+        * AIR_SPECIFIC_HEAT
+        * (indoor_temperature - outdoor_measurement.temperature.value());
+    let latent_energy = water_removed / 1000.0 * LATENT_HEAT_OF_VAPORISATION;
+
+    Some(VentilationEnergy {
+        water_removed,
+        sensible_energy: sensible_energy / KJ_PER_KWH,
+        latent_energy: latent_energy / KJ_PER_KWH,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::open_window::{
+        measurement::Measurement, relative_humidity::RelativeHumidity, room::Room,
+        temperature::Temperature,
+    };
+
+    use super::{
+        predict_relative_humidity, ventilation_duration, ventilation_energy, VentilationTarget,
+    };
+
+    macro_rules! predict_relative_humidity_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (target_temperature, exchanged_fraction, expected) = $value;
+
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(22.0), RelativeHumidity::new(65));
+
+                    let outdoor_measurement =
+                        Measurement::new(Temperature::new(2.0), RelativeHumidity::new(80));
+
+                    let relative_humidity = predict_relative_humidity(
+                        &indoor_measurement,
+                        &outdoor_measurement,
+                        &Temperature::new(target_temperature),
+                        exchanged_fraction,
+                    );
+
+                    assert_eq!(expected, format!("{relative_humidity:.2}"));
+                }
+             )*
+        }
+    }
+
+    predict_relative_humidity_tests! {
+        full_exchange: (20.0, 1.0, "24.19"),
+        half_exchange: (20.0, 0.5, "48.84"),
+        no_exchange: (20.0, 0.0, "73.50"),
+        fraction_above_one: (20.0, 2.0, "24.19"),
+        condensation: (10.0, 0.0, "100.00"),
+    }
+
+    macro_rules! ventilation_duration_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (moisture_sources, target, expected) = $value;
+
+                    let room = Room::new(40.0, 6.0, moisture_sources);
+
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(22.0), RelativeHumidity::new(65));
+
+                    let outdoor_measurement =
+                        Measurement::new(Temperature::new(2.0), RelativeHumidity::new(80));
+
+                    let minutes = ventilation_duration(
+                        &room,
+                        &indoor_measurement,
+                        &outdoor_measurement,
+                        &target,
+                    );
+
+                    assert_eq!(expected, minutes.map(|minutes| format!("{minutes:.2}")).as_deref());
+                }
+             )*
+        }
+    }
+
+    ventilation_duration_tests! {
+        relative_humidity_target: (
+            0.0,
+            VentilationTarget::RelativeHumidity(RelativeHumidity::new(50)),
+            Some("4.22")
+        ),
+        relative_humidity_target_with_moisture_sources: (
+            200.0,
+            VentilationTarget::RelativeHumidity(RelativeHumidity::new(50)),
+            Some("4.81")
+        ),
+        dew_point_target: (0.0, VentilationTarget::DewPoint(8.0), Some("8.20")),
+        already_reached: (
+            0.0,
+            VentilationTarget::RelativeHumidity(RelativeHumidity::new(70)),
+            Some("0.00")
+        ),
+        unreachable: (
+            0.0,
+            VentilationTarget::RelativeHumidity(RelativeHumidity::new(20)),
+            None
+        ),
+        nan_dew_point_target: (0.0, VentilationTarget::DewPoint(f64::NAN), None),
+        infinite_dew_point_target: (0.0, VentilationTarget::DewPoint(f64::NEG_INFINITY), None),
+    }
+
+    macro_rules! ventilation_energy_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let ((outdoor_temperature, outdoor_humidity), moisture_sources, minutes, expected) =
+                        $value;
+
+                    let room = Room::new(50.0, 6.0, moisture_sources);
+
+                    let indoor_measurement =
+                        Measurement::new(Temperature::new(20.0), RelativeHumidity::new(60));
+
+                    let outdoor_measurement = Measurement::new(
+                        Temperature::new(outdoor_temperature),
+                        RelativeHumidity::new(outdoor_humidity),
+                    );
+
+                    let energy = ventilation_energy(
+                        &room,
+                        &indoor_measurement,
+                        &outdoor_measurement,
+                        minutes,
+                    )
+                    .unwrap();
+
+                    assert_eq!(
+                        expected,
+                        (
+                            format!("{:.2}", energy.water_removed).as_str(),
+                            format!("{:.2}", energy.sensible_energy).as_str(),
+                            format!("{:.2}", energy.latent_energy).as_str(),
+                            format!("{:.2}", energy.cost(0.3)).as_str(),
+                        )
+                    );
+                }
+             )*
+        }
+    }
+
+    ventilation_energy_tests! {
+        freezing_outside: ((-5.0, 80), 0.0, 10.0, ("248.23", "0.42", "0.17", "0.18")),
+        with_moisture_sources: ((-5.0, 80), 200.0, 10.0, ("227.16", "0.42", "0.16", "0.17")),
+        warmer_outside: ((25.0, 40), 0.0, 10.0, ("31.63", "-0.08", "0.02", "-0.02")),
+        window_closed: ((-5.0, 80), 0.0, 0.0, ("0.00", "0.00", "0.00", "0.00")),
+    }
+
+    #[test]
+    fn ventilation_energy_boiling() {
+        let room = Room::new(50.0, 6.0, 0.0);
+        let indoor_measurement =
+            Measurement::new(Temperature::new(100.0), RelativeHumidity::new(100));
+        let outdoor_measurement =
+            Measurement::new(Temperature::new(-5.0), RelativeHumidity::new(80));
+
+        assert_eq!(
+            None,
+            ventilation_energy(&room, &indoor_measurement, &outdoor_measurement, 10.0)
+        );
+    }
+}
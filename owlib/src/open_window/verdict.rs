@@ -0,0 +1,114 @@
+use core::fmt;
+
+/// The "open window" verdict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Verdict {
+    /// Opening windows will decrease indoor humidity.
+    Open,
+    /// Opening windows will not decrease indoor humidity, or it is not advisable.
+    Close,
+    /// Opening windows makes little difference.
+    Neutral,
+}
+
+impl Verdict {
+    /// Returns a machine-readable code of the verdict.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Neutral => "neutral",
+        }
+    }
+
+    /// Returns a human-readable message describing the verdict.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::Open => "Open window!",
+            Self::Close => "Close window!",
+            Self::Neutral => "No need to open window.",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+/// A reason which contributed to the [Verdict].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Reason {
+    /// Outdoor dew point is lower than indoor dew point.
+    OutdoorDrier,
+    /// Outdoor dew point is not lower than indoor dew point.
+    OutdoorWetter,
+    /// Dew point difference is smaller than the required margin.
+    DeltaBelowMargin,
+    /// Outdoor temperature is below the allowed minimum.
+    TooColdOutside,
+    /// Outdoor temperature is above the allowed maximum.
+    TooHotOutside,
+    /// Indoor relative humidity is already at or below the desired level.
+    IndoorAlreadyDry,
+    /// Indoor dew point is at or above outdoor temperature, so indoor air
+    /// condenses on the coldest surfaces (window panes, thermal bridges).
+    CondensationRisk,
+    /// Heat lost by airing is too high compared to the removed moisture.
+    HeatLossTooHigh,
+    /// Dew point difference is below the required margin (or outdoor dew point
+    /// is not lower at all), but windows stay open, as the difference is still
+    /// within the hysteresis band.
+    WithinHysteresis,
+}
+
+impl Reason {
+    /// Returns a machine-readable code of the reason.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::OutdoorDrier => "outdoor_drier",
+            Self::OutdoorWetter => "outdoor_wetter",
+            Self::DeltaBelowMargin => "delta_below_margin",
+            Self::TooColdOutside => "too_cold_outside",
+            Self::TooHotOutside => "too_hot_outside",
+            Self::IndoorAlreadyDry => "indoor_already_dry",
+            Self::CondensationRisk => "condensation_risk",
+            Self::HeatLossTooHigh => "heat_loss_too_high",
+            Self::WithinHysteresis => "within_hysteresis",
+        }
+    }
+
+    /// Returns a human-readable message describing the reason.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::OutdoorDrier => "Outdoor air is drier than indoor air.",
+            Self::OutdoorWetter => "Outdoor air is not drier than indoor air.",
+            Self::DeltaBelowMargin => {
+                "Dew point difference is too small to make airing worthwhile."
+            }
+            Self::TooColdOutside => "It is too cold outside.",
+            Self::TooHotOutside => "It is too hot outside.",
+            Self::IndoorAlreadyDry => "Indoor air is already dry enough.",
+            Self::CondensationRisk => "Indoor air may condense on cold surfaces.",
+            Self::HeatLossTooHigh => "Airing would waste too much heat.",
+            Self::WithinHysteresis => "Dew point difference is within the hysteresis band.",
+        }
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
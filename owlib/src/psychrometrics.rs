@@ -0,0 +1,507 @@
+use crate::{
+    open_window::{
+        dew_point::DewPointFormula,
+        measurement::Measurement,
+        pressure::{Pressure, STANDARD_PRESSURE},
+        relative_humidity::RelativeHumidity,
+        temperature::Temperature,
+    },
+    Error, Field,
+};
+
+/// Ratio of molar masses of water vapour and dry air.
+const MOLAR_MASS_RATIO: f64 = 0.62198;
+
+/// Specific gas constant of water vapour expressed in J/(kg·K).
+const WATER_VAPOUR_GAS_CONSTANT: f64 = 461.5;
+
+/// Specific gas constant of dry air expressed in J/(kg·K).
+const DRY_AIR_GAS_CONSTANT: f64 = 287.05;
+
+/// Specific heat capacity of dry air expressed in kJ/(kg·K).
+pub(crate) const AIR_SPECIFIC_HEAT: f64 = 1.006;
+
+/// Specific heat capacity of water vapour expressed in kJ/(kg·K).
+const VAPOUR_SPECIFIC_HEAT: f64 = 1.86;
+
+/// Specific heat capacity of liquid water expressed in kJ/(kg·K).
+const WATER_SPECIFIC_HEAT: f64 = 4.186;
+
+/// Latent heat of vaporisation of water at 0 °C expressed in kJ/kg.
+pub(crate) const LATENT_HEAT_OF_VAPORISATION: f64 = 2501.0;
+
+/// Offset between Celsius and Kelvin scales.
+const ZERO_CELSIUS_IN_KELVIN: f64 = 273.15;
+
+/// Number of bisection steps used while searching for a wet-bulb temperature.
+const WET_BULB_ITERATIONS: usize = 60;
+
+/// Calculates saturation vapour pressure over water (hPa) at provided temperature.
+///
+/// Uses the same formula as [Measurement::calculate_dew_point].
+pub fn saturation_vapour_pressure(temperature: &Temperature) -> f64 {
+    saturation_vapour_pressure_at(temperature.value())
+}
+
+/// Calculates actual (partial) vapour pressure (hPa) of the measured air.
+pub fn vapour_pressure(measurement: &Measurement) -> f64 {
+    measurement.relative_humidity.percent() / 100.0
+        * saturation_vapour_pressure(&measurement.temperature)
+}
+
+/// Calculates absolute humidity - mass of water vapour in a cubic metre of
+/// the measured air - expressed in g/m³.
+///
+/// Absolute humidity depends only on vapour pressure and temperature, so -
+/// unlike the remaining quantities - it is not affected by barometric pressure.
+pub fn absolute_humidity(measurement: &Measurement) -> f64 {
+    absolute_humidity_at(
+        vapour_pressure(measurement),
+        measurement.temperature.value(),
+    )
+}
+
+/// Calculates humidity ratio (mixing ratio) - mass of water vapour per mass of
+/// dry air - expressed in g/kg.
+///
+/// Uses measured barometric pressure, or [STANDARD_PRESSURE] if the
+/// `Measurement` does not hold one.
+///
+/// Returns `None` if vapour pressure is not below barometric pressure - water
+/// boils at such a temperature and there is no dry air left. This quantity,
+/// like the remaining ones depending on pressure, is only defined below it.
+pub fn humidity_ratio(measurement: &Measurement) -> Option<f64> {
+    humidity_ratio_at(vapour_pressure(measurement), pressure(measurement))
+        .map(|humidity_ratio| humidity_ratio * 1000.0)
+}
+
+/// Calculates specific humidity - mass of water vapour per mass of moist
+/// air - expressed in g/kg.
+///
+/// Returns `None` if vapour pressure is not below barometric pressure.
+pub fn specific_humidity(measurement: &Measurement) -> Option<f64> {
+    specific_humidity_at(vapour_pressure(measurement), pressure(measurement))
+        .map(|specific_humidity| specific_humidity * 1000.0)
+}
+
+/// Calculates density of the measured (moist) air expressed in kg/m³.
+///
+/// Returns `None` if vapour pressure is not below barometric pressure.
+pub fn air_density(measurement: &Measurement) -> Option<f64> {
+    air_density_at(
+        vapour_pressure(measurement),
+        pressure(measurement),
+        measurement.temperature.value(),
+    )
+}
+
+/// Calculates specific enthalpy of the moist air expressed in kJ per kg of dry air.
+///
+/// Returns `None` if vapour pressure is not below barometric pressure.
+pub fn enthalpy(measurement: &Measurement) -> Option<f64> {
+    humidity_ratio_at(vapour_pressure(measurement), pressure(measurement))
+        .map(|humidity_ratio| enthalpy_at(humidity_ratio, measurement.temperature.value()))
+}
+
+/// Calculates thermodynamic wet-bulb temperature (°C) of the measured air.
+///
+/// The value is found numerically, by searching between the dew point and the
+/// air temperature for a wet-bulb temperature which yields measured humidity ratio.
+///
+/// Returns `None` if vapour pressure is not below barometric pressure.
+pub fn wet_bulb_temperature(measurement: &Measurement) -> Option<f64> {
+    let pressure = pressure(measurement);
+
+    humidity_ratio_at(vapour_pressure(measurement), pressure).map(|humidity_ratio| {
+        wet_bulb_temperature_at(
+            humidity_ratio,
+            pressure,
+            measurement.temperature.value(),
+            measurement.calculate_dew_point(),
+        )
+    })
+}
+
+/// Calculates relative humidity of air at provided temperature which has
+/// provided dew point (°C).
+///
+/// Returns an [Error] if the dew point is above the
+/// temperature (air would be supersaturated) or very far below it.
+pub fn relative_humidity_from_dew_point(
+    temperature: &Temperature,
+    dew_point: f64,
+) -> Result<RelativeHumidity, Error> {
+    RelativeHumidity::try_from_percent(
+        saturation_vapour_pressure_at(dew_point) / saturation_vapour_pressure(temperature) * 100.0,
+    )
+}
+
+/// Calculates a temperature (°C) to which the measured air has to be brought
+/// (without adding or removing water vapour) to reach provided relative humidity.
+///
+/// Returns `None` if the relative humidity can not be reached at any
+/// temperature - when either the target or the measured air is completely
+/// dry (0%).
+pub fn temperature_for_relative_humidity(
+    measurement: &Measurement,
+    relative_humidity: &RelativeHumidity,
+) -> Option<f64> {
+    let vapour_pressure = vapour_pressure(measurement);
+    if vapour_pressure <= 0.0 || relative_humidity.percent() <= 0.0 {
+        return None;
+    }
+
+    Some(
+        DewPointFormula::default()
+            .dew_point(vapour_pressure / (relative_humidity.percent() / 100.0)),
+    )
+}
+
+/// Calculates relative humidity from dry-bulb and wet-bulb temperature
+/// readings of a psychrometer.
+///
+/// Uses provided barometric pressure, or [STANDARD_PRESSURE] if it is `None`.
+///
+/// Returns an [Error] if the readings do not describe a valid relative
+/// humidity, e.g. the wet-bulb temperature is above the dry-bulb temperature,
+/// or [Error::AboveBoilingPoint] if the wet-bulb temperature reaches the
+/// boiling point at provided pressure.
+pub fn relative_humidity_from_wet_bulb(
+    temperature: &Temperature,
+    wet_bulb_temperature: &Temperature,
+    pressure: Option<&Pressure>,
+) -> Result<RelativeHumidity, Error> {
+    let pressure = pressure.map_or(STANDARD_PRESSURE, Pressure::value);
+    let humidity_ratio =
+        wet_bulb_humidity_ratio(temperature.value(), wet_bulb_temperature.value(), pressure)
+            .ok_or(Error::AboveBoilingPoint {
+                field: Field::WetBulbTemperature,
+                value: wet_bulb_temperature.value(),
+                pressure,
+            })?;
+    let vapour_pressure = humidity_ratio * pressure / (MOLAR_MASS_RATIO + humidity_ratio);
+
+    RelativeHumidity::try_from_percent(
+        vapour_pressure / saturation_vapour_pressure(temperature) * 100.0,
+    )
+}
+
+pub(crate) fn pressure(measurement: &Measurement) -> f64 {
+    measurement
+        .pressure
+        .as_ref()
+        .map_or(STANDARD_PRESSURE, Pressure::value)
+}
+
+pub(crate) fn saturation_vapour_pressure_at(temperature: f64) -> f64 {
+    DewPointFormula::default().saturation_vapour_pressure(temperature)
+}
+
+/// Absolute humidity (g/m³) of air with provided vapour pressure (hPa) and
+/// temperature (°C).
+pub(crate) fn absolute_humidity_at(vapour_pressure: f64, temperature: f64) -> f64 {
+    let vapour_pressure_pa = vapour_pressure * 100.0;
+    let temperature_k = temperature + ZERO_CELSIUS_IN_KELVIN;
+
+    vapour_pressure_pa / (WATER_VAPOUR_GAS_CONSTANT * temperature_k) * 1000.0
+}
+
+/// Humidity ratio (kg/kg) of air with provided vapour pressure and barometric
+/// pressure (hPa), or `None` if vapour pressure is not below barometric pressure.
+pub(crate) fn humidity_ratio_at(vapour_pressure: f64, pressure: f64) -> Option<f64> {
+    (vapour_pressure < pressure)
+        .then(|| MOLAR_MASS_RATIO * vapour_pressure / (pressure - vapour_pressure))
+}
+
+/// Specific humidity (kg/kg) of air with provided vapour pressure and barometric
+/// pressure (hPa), or `None` if vapour pressure is not below barometric pressure.
+pub(crate) fn specific_humidity_at(vapour_pressure: f64, pressure: f64) -> Option<f64> {
+    (vapour_pressure < pressure).then(|| {
+        MOLAR_MASS_RATIO * vapour_pressure / (pressure - (1.0 - MOLAR_MASS_RATIO) * vapour_pressure)
+    })
+}
+
+/// Density (kg/m³) of moist air with provided vapour pressure, barometric
+/// pressure (hPa) and temperature (°C), or `None` if vapour pressure is not
+/// below barometric pressure.
+pub(crate) fn air_density_at(vapour_pressure: f64, pressure: f64, temperature: f64) -> Option<f64> {
+    (vapour_pressure < pressure).then(|| {
+        let vapour_pressure_pa = vapour_pressure * 100.0;
+        let pressure_pa = pressure * 100.0;
+        let temperature_k = temperature + ZERO_CELSIUS_IN_KELVIN;
+
+        (pressure_pa - vapour_pressure_pa) / (DRY_AIR_GAS_CONSTANT * temperature_k)
+            + vapour_pressure_pa / (WATER_VAPOUR_GAS_CONSTANT * temperature_k)
+    })
+}
+
+/// Specific enthalpy (kJ/kg of dry air) of air with provided humidity ratio
+/// (kg/kg) and temperature (°C).
+pub(crate) fn enthalpy_at(humidity_ratio: f64, temperature: f64) -> f64 {
+    AIR_SPECIFIC_HEAT * temperature
+        + humidity_ratio * (LATENT_HEAT_OF_VAPORISATION + VAPOUR_SPECIFIC_HEAT * temperature)
+}
+
+/// Wet-bulb temperature (°C) of air with provided humidity ratio (kg/kg),
+/// barometric pressure (hPa), temperature and dew point (°C).
+pub(crate) fn wet_bulb_temperature_at(
+    humidity_ratio: f64,
+    pressure: f64,
+    temperature: f64,
+    dew_point: f64,
+) -> f64 {
+    let mut low = dew_point;
+    let mut high = temperature;
+
+    for _ in 0..WET_BULB_ITERATIONS {
+        let middle = (low + high) / 2.0;
+        if wet_bulb_humidity_ratio(temperature, middle, pressure)
+            .is_some_and(|wet_bulb_ratio| wet_bulb_ratio < humidity_ratio)
+        {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    (low + high) / 2.0
+}
+
+/// Humidity ratio (kg/kg) of air at `temperature` which has `wet_bulb` wet-bulb
+/// temperature (ASHRAE Fundamentals, psychrometrics chapter), or `None` if the
+/// wet-bulb temperature reaches the boiling point.
+fn wet_bulb_humidity_ratio(temperature: f64, wet_bulb: f64, pressure: f64) -> Option<f64> {
+    let saturated_ratio = humidity_ratio_at(saturation_vapour_pressure_at(wet_bulb), pressure)?;
+
+    Some(
+        ((LATENT_HEAT_OF_VAPORISATION - (WATER_SPECIFIC_HEAT - VAPOUR_SPECIFIC_HEAT) * wet_bulb)
+            * saturated_ratio
+            - AIR_SPECIFIC_HEAT * (temperature - wet_bulb))
+            / (LATENT_HEAT_OF_VAPORISATION + VAPOUR_SPECIFIC_HEAT * temperature
+                - WATER_SPECIFIC_HEAT * wet_bulb),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        open_window::{
+            limits::Limits, measurement::Measurement, pressure::Pressure,
+            relative_humidity::RelativeHumidity, temperature::Temperature,
+        },
+        Error, Field,
+    };
+
+    macro_rules! psychrometrics_tests {
+        ($($name:ident: $function:path, $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (temperature, relative_humidity, expected) = $value;
+
+                    let measurement = Measurement::new(
+                        Temperature::new(temperature),
+                        RelativeHumidity::new(relative_humidity),
+                    );
+
+                    // Functions depending on barometric pressure return an `Option`.
+                    let value: Option<f64> = $function(&measurement).into();
+
+                    assert_eq!(expected, format!("{:.2}", value.unwrap()));
+                }
+             )*
+        }
+    }
+
+    psychrometrics_tests! {
+        vapour_pressure_indoor: super::vapour_pressure, (18.0, 55, "11.33"),
+        vapour_pressure_outdoor: super::vapour_pressure, (-5.0, 80, "3.38"),
+        absolute_humidity_indoor: super::absolute_humidity, (18.0, 55, "8.43"),
+        absolute_humidity_hot: super::absolute_humidity, (30.0, 70, "21.20"),
+        humidity_ratio_indoor: super::humidity_ratio, (18.0, 55, "7.03"),
+        humidity_ratio_hot: super::humidity_ratio, (30.0, 70, "18.75"),
+        specific_humidity_indoor: super::specific_humidity, (18.0, 55, "6.98"),
+        air_density_indoor: super::air_density, (18.0, 55, "1.21"),
+        air_density_outdoor: super::air_density, (-5.0, 80, "1.31"),
+        enthalpy_indoor: super::enthalpy, (18.0, 55, "35.93"),
+        enthalpy_outdoor: super::enthalpy, (-5.0, 80, "0.15"),
+        wet_bulb_indoor: super::wet_bulb_temperature, (18.0, 55, "12.79"),
+        wet_bulb_outdoor: super::wet_bulb_temperature, (-5.0, 80, "-5.88"),
+        wet_bulb_hot: super::wet_bulb_temperature, (30.0, 70, "25.51"),
+    }
+
+    #[test]
+    fn saturation_vapour_pressure() {
+        let value = super::saturation_vapour_pressure(&Temperature::new(18.0));
+
+        assert_eq!("20.60", format!("{value:.2}"));
+    }
+
+    macro_rules! altitude_tests {
+        ($($name:ident: $function:path, $expected:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let measurement =
+                        Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55))
+                            .with_pressure(Pressure::from_altitude(1500.0).unwrap());
+
+                    let value: Option<f64> = $function(&measurement).into();
+
+                    assert_eq!($expected, format!("{:.2}", value.unwrap()));
+                }
+             )*
+        }
+    }
+
+    altitude_tests! {
+        absolute_humidity_altitude: super::absolute_humidity, "8.43",
+        humidity_ratio_altitude: super::humidity_ratio, "8.45",
+        specific_humidity_altitude: super::specific_humidity, "8.38",
+        air_density_altitude: super::air_density, "1.01",
+        enthalpy_altitude: super::enthalpy, "39.51",
+        wet_bulb_altitude: super::wet_bulb_temperature, "12.40",
+    }
+
+    macro_rules! boiling_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (temperature, relative_humidity, pressure) = $value;
+                    let measurement = Measurement::new(
+                        Temperature::new(temperature),
+                        RelativeHumidity::new(relative_humidity),
+                    );
+                    let measurement = match pressure {
+                        Some(pressure) => {
+                            measurement.with_pressure(Pressure::try_new(pressure).unwrap())
+                        }
+                        None => measurement,
+                    };
+
+                    assert_eq!(None, super::humidity_ratio(&measurement));
+                    assert_eq!(None, super::specific_humidity(&measurement));
+                    assert_eq!(None, super::air_density(&measurement));
+                    assert_eq!(None, super::enthalpy(&measurement));
+                    assert_eq!(None, super::wet_bulb_temperature(&measurement));
+                }
+             )*
+        }
+    }
+
+    boiling_tests! {
+        boiling_at_max_temperature: (100.0, 100, None),
+        boiling_at_low_pressure: (70.0, 100, Some(300.0)),
+    }
+
+    #[test]
+    fn max_temperature_below_boiling_point() {
+        // Vapour pressure is about 520 hPa, while saturation vapour pressure at 100 °C
+        // exceeds standard pressure.
+        let measurement = Measurement::new(Temperature::new(100.0), RelativeHumidity::new(50));
+
+        let humidity_ratio = super::humidity_ratio(&measurement).unwrap();
+        let wet_bulb_temperature = super::wet_bulb_temperature(&measurement).unwrap();
+
+        assert_eq!("656.71", format!("{humidity_ratio:.2}"));
+        assert!(wet_bulb_temperature < 100.0);
+    }
+
+    #[test]
+    fn relative_humidity_from_dew_point() {
+        let relative_humidity =
+            super::relative_humidity_from_dew_point(&Temperature::new(20.0), 10.0).unwrap();
+
+        assert_eq!("52.54", format!("{:.2}", relative_humidity.percent()));
+    }
+
+    #[test]
+    fn temperature_for_relative_humidity() {
+        let measurement = Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+
+        let temperature =
+            super::temperature_for_relative_humidity(&measurement, &RelativeHumidity::new(70))
+                .unwrap();
+
+        assert_eq!("14.22", format!("{temperature:.2}"));
+    }
+
+    #[test]
+    fn temperature_for_relative_humidity_of_dry_air() {
+        let limits = Limits::default().relative_humidity(0.0, 100.0);
+        let dry_air = RelativeHumidity::try_from_percent_with_limits(0.0, &limits).unwrap();
+        let measurement = Measurement::new(Temperature::new(18.0), RelativeHumidity::new(55));
+
+        assert_eq!(
+            None,
+            super::temperature_for_relative_humidity(&measurement, &dry_air)
+        );
+        assert_eq!(
+            None,
+            super::temperature_for_relative_humidity(
+                &Measurement::new(Temperature::new(18.0), dry_air),
+                &RelativeHumidity::new(55)
+            )
+        );
+    }
+
+    macro_rules! relative_humidity_from_wet_bulb_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (temperature, wet_bulb_temperature, expected) = $value;
+
+                    let relative_humidity = super::relative_humidity_from_wet_bulb(
+                        &Temperature::new(temperature),
+                        &Temperature::new(wet_bulb_temperature),
+                        None,
+                    )
+                    .unwrap();
+
+                    assert_eq!(expected, format!("{:.2}", relative_humidity.percent()));
+                }
+             )*
+        }
+    }
+
+    relative_humidity_from_wet_bulb_tests! {
+        relative_humidity_from_wet_bulb_indoor: (18.0, 12.79, "55.03"),
+        relative_humidity_from_wet_bulb_hot: (30.0, 25.51, "70.01"),
+        relative_humidity_from_wet_bulb_dry: (20.0, 10.0, "24.51"),
+    }
+
+    #[test]
+    fn relative_humidity_from_wet_bulb_above_dry_bulb() {
+        let relative_humidity = super::relative_humidity_from_wet_bulb(
+            &Temperature::new(18.0),
+            &Temperature::new(20.0),
+            None,
+        );
+
+        assert!(relative_humidity.is_err());
+    }
+
+    #[test]
+    fn relative_humidity_from_wet_bulb_at_boiling_point() {
+        let relative_humidity = super::relative_humidity_from_wet_bulb(
+            &Temperature::new(100.0),
+            &Temperature::new(100.0),
+            None,
+        );
+
+        assert_eq!(
+            Err(Error::AboveBoilingPoint {
+                field: Field::WetBulbTemperature,
+                value: 100.0,
+                pressure: 1013.25,
+            }),
+            relative_humidity
+        );
+        assert_eq!(
+            "wet-bulb temperature must be below the boiling point at 1013.25 hPa, got 100",
+            relative_humidity.unwrap_err().to_string()
+        );
+    }
+}
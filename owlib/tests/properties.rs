@@ -0,0 +1,139 @@
+//! Property-based tests of dew point invariants, which hold for any valid measurement.
+
+use owlib::open_window::{
+    dew_point::DewPointFormula,
+    measurement::Measurement,
+    relative_humidity::{RelativeHumidity, MAX_HUMIDITY, MIN_HUMIDITY},
+    temperature::{Temperature, MAX_TEMP, MIN_TEMP},
+};
+use proptest::prelude::*;
+
+/// Tolerance of floating point rounding errors (°C).
+const EPSILON: f64 = 1e-9;
+
+/// Formulas yielding a dew point over water. [DewPointFormula::WmoOverIce] is
+/// covered by frost point invariants, as a frost point may exceed air temperature.
+const FORMULAS: [DewPointFormula; 4] = [
+    DewPointFormula::MagnusTetens,
+    DewPointFormula::AlduchovEskridge,
+    DewPointFormula::ArdenBuck,
+    DewPointFormula::Sonntag,
+];
+
+fn dew_point(formula: DewPointFormula, temperature: f64, relative_humidity: f64) -> f64 {
+    Measurement::new(
+        Temperature::new(temperature),
+        RelativeHumidity::from_percent(relative_humidity),
+    )
+    .calculate_dew_point_with(formula)
+}
+
+fn formula() -> impl Strategy<Value = DewPointFormula> {
+    proptest::sample::select(&FORMULAS[..])
+}
+
+fn temperature() -> impl Strategy<Value = f64> {
+    MIN_TEMP..=MAX_TEMP
+}
+
+fn temperature_below_freezing() -> impl Strategy<Value = f64> {
+    MIN_TEMP..0.0
+}
+
+fn relative_humidity() -> impl Strategy<Value = f64> {
+    MIN_HUMIDITY as f64..=MAX_HUMIDITY as f64
+}
+
+proptest! {
+    #[test]
+    fn dew_point_never_exceeds_temperature(
+        formula in formula(),
+        temperature in temperature(),
+        relative_humidity in relative_humidity(),
+    ) {
+        let dew_point = dew_point(formula, temperature, relative_humidity);
+
+        prop_assert!(dew_point <= temperature + EPSILON);
+    }
+
+    #[test]
+    fn dew_point_equals_temperature_at_saturation(
+        formula in formula(),
+        temperature in temperature(),
+    ) {
+        let dew_point = dew_point(formula, temperature, MAX_HUMIDITY as f64);
+
+        prop_assert!((dew_point - temperature).abs() <= EPSILON);
+    }
+
+    #[test]
+    fn dew_point_is_monotonic_in_relative_humidity(
+        formula in formula(),
+        temperature in temperature(),
+        relative_humidity in relative_humidity(),
+        other_relative_humidity in relative_humidity(),
+    ) {
+        let (lower, higher) = if relative_humidity <= other_relative_humidity {
+            (relative_humidity, other_relative_humidity)
+        } else {
+            (other_relative_humidity, relative_humidity)
+        };
+
+        prop_assert!(
+            dew_point(formula, temperature, lower)
+                <= dew_point(formula, temperature, higher) + EPSILON
+        );
+    }
+
+    #[test]
+    fn dew_point_is_monotonic_in_temperature(
+        formula in formula(),
+        temperature in temperature(),
+        other_temperature in temperature(),
+        relative_humidity in relative_humidity(),
+    ) {
+        let (lower, higher) = if temperature <= other_temperature {
+            (temperature, other_temperature)
+        } else {
+            (other_temperature, temperature)
+        };
+
+        prop_assert!(
+            dew_point(formula, lower, relative_humidity)
+                <= dew_point(formula, higher, relative_humidity) + EPSILON
+        );
+    }
+
+    #[test]
+    fn frost_point_is_between_dew_point_and_freezing(
+        temperature in temperature_below_freezing(),
+        relative_humidity in relative_humidity(),
+    ) {
+        // Vapour pressure over ice is lower than over supercooled water, so
+        // vapour deposits as frost before it would condense as dew.
+        let frost_point = dew_point(DewPointFormula::WmoOverIce, temperature, relative_humidity);
+
+        prop_assert!(
+            dew_point(DewPointFormula::Sonntag, temperature, relative_humidity) <= frost_point
+        );
+        prop_assert!(frost_point < 0.0);
+    }
+
+    #[test]
+    fn frost_point_is_monotonic_in_relative_humidity(
+        temperature in temperature_below_freezing(),
+        relative_humidity in relative_humidity(),
+        other_relative_humidity in relative_humidity(),
+    ) {
+        let (lower, higher) = if relative_humidity <= other_relative_humidity {
+            (relative_humidity, other_relative_humidity)
+        } else {
+            (other_relative_humidity, relative_humidity)
+        };
+
+        prop_assert!(
+            dew_point(DewPointFormula::WmoOverIce, temperature, lower)
+                <= dew_point(DewPointFormula::WmoOverIce, temperature, higher) + EPSILON
+        );
+    }
+}
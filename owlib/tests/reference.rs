@@ -0,0 +1,247 @@
+//! Validates dew point formulas against published psychrometric tables.
+//!
+//! Reference values are checked in under `tests/data`, each file documents its
+//! source. Tolerances are documented per formula and temperature range, as the
+//! empirical formulas are fitted to meteorological temperatures and drift away
+//! from the reference outside of them. Below 0 °C formulas over water are
+//! validated against supercooled water, while [DewPointFormula::WmoOverIce]
+//! frost points are validated against vapour pressure over ice.
+
+use owlib::open_window::{
+    dew_point::DewPointFormula, measurement::Measurement, relative_humidity::RelativeHumidity,
+    temperature::Temperature,
+};
+
+const SATURATION_VAPOUR_PRESSURE: &str = include_str!("data/saturation_vapour_pressure.csv");
+const SUPERCOOLED_WATER: &str =
+    include_str!("data/saturation_vapour_pressure_supercooled_water.csv");
+const ICE: &str = include_str!("data/saturation_vapour_pressure_ice.csv");
+
+/// Formulas for water validated against the reference tables.
+const FORMULAS: [DewPointFormula; 4] = [
+    DewPointFormula::MagnusTetens,
+    DewPointFormula::AlduchovEskridge,
+    DewPointFormula::ArdenBuck,
+    DewPointFormula::Sonntag,
+];
+
+/// Accepted deviation of a formula from the reference table.
+struct Tolerance {
+    formula: DewPointFormula,
+    /// Upper bound (inclusive) of the temperature range the tolerance applies to (°C).
+    max_temperature: f64,
+    /// Maximum relative error of saturation vapour pressure (%).
+    saturation_vapour_pressure: f64,
+    /// Maximum absolute error of dew point calculated from reference vapour pressure (°C).
+    dew_point: f64,
+}
+
+/// Tolerances, each applying from the previous range of the same formula (or
+/// -40 °C) up to its `max_temperature`. Ranges up to 0 °C apply to supercooled
+/// water.
+const TOLERANCES: [Tolerance; 10] = [
+    // Simple Magnus approximation, diverges most over supercooled water.
+    Tolerance {
+        formula: DewPointFormula::MagnusTetens,
+        max_temperature: 0.0,
+        saturation_vapour_pressure: 2.0,
+        dew_point: 0.2,
+    },
+    Tolerance {
+        formula: DewPointFormula::MagnusTetens,
+        max_temperature: 100.0,
+        saturation_vapour_pressure: 0.6,
+        dew_point: 0.15,
+    },
+    // Fitted for -40..=50 °C, diverges above.
+    Tolerance {
+        formula: DewPointFormula::AlduchovEskridge,
+        max_temperature: 0.0,
+        saturation_vapour_pressure: 0.35,
+        dew_point: 0.04,
+    },
+    Tolerance {
+        formula: DewPointFormula::AlduchovEskridge,
+        max_temperature: 50.0,
+        saturation_vapour_pressure: 0.3,
+        dew_point: 0.05,
+    },
+    Tolerance {
+        formula: DewPointFormula::AlduchovEskridge,
+        max_temperature: 100.0,
+        saturation_vapour_pressure: 3.0,
+        dew_point: 0.75,
+    },
+    // Most accurate, within 0.15% up to the boiling point.
+    Tolerance {
+        formula: DewPointFormula::ArdenBuck,
+        max_temperature: 0.0,
+        saturation_vapour_pressure: 0.4,
+        dew_point: 0.04,
+    },
+    Tolerance {
+        formula: DewPointFormula::ArdenBuck,
+        max_temperature: 100.0,
+        saturation_vapour_pressure: 0.15,
+        dew_point: 0.05,
+    },
+    // Fitted for -45..=60 °C, diverges above.
+    Tolerance {
+        formula: DewPointFormula::Sonntag,
+        max_temperature: 0.0,
+        saturation_vapour_pressure: 0.6,
+        dew_point: 0.06,
+    },
+    Tolerance {
+        formula: DewPointFormula::Sonntag,
+        max_temperature: 50.0,
+        saturation_vapour_pressure: 0.35,
+        dew_point: 0.06,
+    },
+    Tolerance {
+        formula: DewPointFormula::Sonntag,
+        max_temperature: 100.0,
+        saturation_vapour_pressure: 2.5,
+        dew_point: 0.7,
+    },
+];
+
+/// Accepted deviation of a frost point calculated by [DewPointFormula::WmoOverIce]
+/// from the ice reference table.
+struct FrostPointTolerance {
+    /// Upper bound (inclusive) of the temperature range the tolerance applies to (°C).
+    max_temperature: f64,
+    /// Maximum absolute error of frost point calculated from reference vapour pressure (°C).
+    frost_point: f64,
+}
+
+/// Tolerances, each applying from the previous range (or -80 °C) up to its
+/// `max_temperature`. WMO coefficients are fitted for -65..=0 °C.
+const FROST_POINT_TOLERANCES: [FrostPointTolerance; 2] = [
+    FrostPointTolerance {
+        max_temperature: -50.0,
+        frost_point: 0.06,
+    },
+    FrostPointTolerance {
+        max_temperature: 0.0,
+        frost_point: 0.01,
+    },
+];
+
+/// Returns `(temperature, saturation vapour pressure)` rows of a reference table.
+fn table(data: &str) -> Vec<(f64, f64)> {
+    data.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (temperature, pressure) = line.split_once(',').unwrap();
+            (
+                temperature.trim().parse().unwrap(),
+                pressure.trim().parse().unwrap(),
+            )
+        })
+        .collect()
+}
+
+/// Returns rows of both reference tables over water, supercooled water first.
+fn water_table() -> Vec<(f64, f64)> {
+    let mut rows = table(SUPERCOOLED_WATER);
+    rows.extend(table(SATURATION_VAPOUR_PRESSURE));
+    rows
+}
+
+/// Returns the tolerance of a formula at provided temperature.
+fn tolerance(formula: DewPointFormula, temperature: f64) -> &'static Tolerance {
+    TOLERANCES
+        .iter()
+        .find(|tolerance| tolerance.formula == formula && temperature <= tolerance.max_temperature)
+        .unwrap_or_else(|| panic!("no tolerance for {formula:?} at {temperature} °C"))
+}
+
+/// Returns the tolerance of [DewPointFormula::WmoOverIce] at provided temperature.
+fn frost_point_tolerance(temperature: f64) -> &'static FrostPointTolerance {
+    FROST_POINT_TOLERANCES
+        .iter()
+        .find(|tolerance| temperature <= tolerance.max_temperature)
+        .unwrap_or_else(|| panic!("no frost point tolerance at {temperature} °C"))
+}
+
+#[test]
+fn reference_tables_are_complete() {
+    let water = water_table();
+    let ice = table(ICE);
+
+    assert_eq!(29, water.len());
+    assert!(water.windows(2).all(|rows| rows[0].0 < rows[1].0));
+    assert_eq!(14, ice.len());
+    assert!(ice.windows(2).all(|rows| rows[0].0 < rows[1].0));
+}
+
+#[test]
+fn saturation_vapour_pressure() {
+    for formula in FORMULAS {
+        for (temperature, pressure) in water_table() {
+            let tolerance = tolerance(formula, temperature);
+            let actual = formula.saturation_vapour_pressure(temperature);
+            let error = (actual - pressure).abs() / pressure * 100.0;
+
+            assert!(
+                error <= tolerance.saturation_vapour_pressure,
+                "{:?} at {temperature} °C: {actual:.3} hPa, expected {pressure} hPa ({error:.3}% off)",
+                formula,
+            );
+        }
+    }
+}
+
+#[test]
+fn dew_point() {
+    for formula in FORMULAS {
+        for (temperature, pressure) in water_table() {
+            let tolerance = tolerance(formula, temperature);
+            let actual = formula.dew_point(pressure);
+            let error = (actual - temperature).abs();
+
+            assert!(
+                error <= tolerance.dew_point,
+                "{:?} at {pressure} hPa: {actual:.3} °C, expected {temperature} °C",
+                formula,
+            );
+        }
+    }
+}
+
+#[test]
+fn frost_point() {
+    for (temperature, pressure) in table(ICE) {
+        let tolerance = frost_point_tolerance(temperature);
+        let actual = DewPointFormula::WmoOverIce.dew_point(pressure);
+        let error = (actual - temperature).abs();
+
+        assert!(
+            error <= tolerance.frost_point,
+            "WmoOverIce at {pressure} hPa: {actual:.3} °C, expected {temperature} °C",
+        );
+    }
+}
+
+#[test]
+fn frost_point_of_measurement() {
+    for (temperature, pressure) in table(ICE).into_iter().filter(|row| row.0 < 0.0) {
+        let tolerance = frost_point_tolerance(temperature);
+        // Relative humidity is defined with respect to water, even below 0 °C.
+        let relative_humidity =
+            pressure / DewPointFormula::WmoOverIce.saturation_vapour_pressure(temperature) * 100.0;
+        let measurement = Measurement::new(
+            Temperature::new(temperature),
+            RelativeHumidity::from_percent(relative_humidity),
+        );
+        let actual = measurement.calculate_frost_point().unwrap();
+        let error = (actual - temperature).abs();
+
+        assert!(
+            error <= tolerance.frost_point,
+            "frost point at {temperature} °C and {relative_humidity:.2}%: {actual:.3} °C",
+        );
+    }
+}
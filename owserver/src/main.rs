@@ -2,15 +2,14 @@ use http_api_problem::HttpApiProblem;
 
 use owlib::open_window::{
     measurement::Measurement,
-    open_window_result,
-    relative_humidity::{RelativeHumidity, RelativeHumidityInvalid},
-    temperature::{Temperature, TemperatureInvalid},
+    strategy::{strategy_by_name, StrategyContext, DEFAULT_STRATEGY, STRATEGY_NAMES},
+    OpenWindowResult,
 };
 
 use tower::ServiceBuilder;
 use tower_http::cors::{Any, CorsLayer};
 
-use std::{collections::HashMap, net::SocketAddr};
+use std::net::SocketAddr;
 
 use axum::{
     extract::OriginalUri,
@@ -20,28 +19,34 @@ use axum::{
     Router,
 };
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
+
+use validated_json::{validation_problem, ValidatedJson, ValidationErrors};
+
+mod validated_json;
 
 #[tokio::main]
 async fn main() {
+    let addr: SocketAddr = SocketAddr::from(([0, 0, 0, 0], 3000));
+
+    axum::Server::bind(&addr)
+        .serve(app().into_make_service())
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
+}
+
+fn app() -> Router {
     let cors = CorsLayer::new()
         .allow_methods(vec![Method::GET, Method::POST])
         .allow_origin(Any);
 
     let service = ServiceBuilder::new().layer(cors);
 
-    let app = Router::new()
+    Router::new()
         .fallback(fallback)
         .layer(service)
-        .route("/open-window", post(post_open_window));
-
-    let addr: SocketAddr = SocketAddr::from(([0, 0, 0, 0], 3000));
-
-    axum::Server::bind(&addr)
-        .serve(app.into_make_service())
-        .with_graceful_shutdown(shutdown_signal())
-        .await
-        .unwrap();
+        .route("/open-window", post(post_open_window))
 }
 
 async fn shutdown_signal() {
@@ -56,100 +61,168 @@ async fn fallback(uri: Uri) -> (StatusCode, String) {
     (StatusCode::NOT_FOUND, format!("No route {uri}"))
 }
 
-#[derive(Serialize)]
-pub struct OpenWindowResponse {
-    pub indoor_dew_point: f64,
-    pub outdoor_dew_point: f64,
-    pub open_window: bool,
-}
-
 #[derive(Deserialize)]
 pub struct OpenWindowRequest {
-    pub indoor_measurement: MeasurementRequest,
-    pub outdoor_measurement: MeasurementRequest,
-}
-
-#[derive(Deserialize)]
-pub struct MeasurementRequest {
-    pub temperature: f64,
-    pub relative_humidity: u8,
+    pub indoor_measurement: Measurement,
+    pub outdoor_measurement: Measurement,
+    /// Name of the decision algorithm, [DEFAULT_STRATEGY] if not provided.
+    pub strategy: Option<String>,
 }
 
 // curl -i -X POST localhost:3000/open-window -H 'Content-Type: application/json' -d '{ "indoor_measurement": { "temperature": 18.0, "relative_humidity": 50 }, "outdoor_measurement": { "temperature": 0.0, "relative_humidity": 85 }}'
 
 async fn post_open_window(
     OriginalUri(uri): OriginalUri,
-    Json(payload): Json<OpenWindowRequest>,
-) -> Result<Json<OpenWindowResponse>, HttpApiProblem> {
-    let indoor_temperature_result = Temperature::try_new(payload.indoor_measurement.temperature);
-    let indoor_humidity_result =
-        RelativeHumidity::try_new(payload.indoor_measurement.relative_humidity);
-    let outdoor_temperature_result = Temperature::try_new(payload.outdoor_measurement.temperature);
-    let outdoor_humidity_result =
-        RelativeHumidity::try_new(payload.outdoor_measurement.relative_humidity);
-
-    if indoor_temperature_result.is_ok()
-        && indoor_humidity_result.is_ok()
-        && outdoor_temperature_result.is_ok()
-        && outdoor_humidity_result.is_ok()
-    {
-        let indoor_measurement = Measurement {
-            temperature: indoor_temperature_result.unwrap(),
-            relative_humidity: indoor_humidity_result.unwrap(),
-        };
-
-        let outdoor_measurement = Measurement {
-            temperature: outdoor_temperature_result.unwrap(),
-            relative_humidity: outdoor_humidity_result.unwrap(),
-        };
-
-        let open_window_result = open_window_result(&indoor_measurement, &outdoor_measurement);
-
-        let open_window_response = OpenWindowResponse {
-            indoor_dew_point: open_window_result.indoor_dew_point,
-            outdoor_dew_point: open_window_result.outdoor_dew_point,
-            open_window: open_window_result.open_window,
-        };
-
-        return Ok(Json(open_window_response));
-    }
-
-    let mut errors: HashMap<String, Vec<String>> = HashMap::new();
+    ValidatedJson(request): ValidatedJson<OpenWindowRequest>,
+) -> Result<Json<OpenWindowResult>, HttpApiProblem> {
+    let strategy_name = request.strategy.as_deref().unwrap_or(DEFAULT_STRATEGY);
 
-    if let Err(TemperatureInvalid(msg)) = indoor_temperature_result {
+    let Some(strategy) = strategy_by_name(strategy_name) else {
+        let mut errors = ValidationErrors::new();
         errors.insert(
-            String::from("indoor_measurement.temperature"),
-            vec![msg.into()],
+            String::from("strategy"),
+            vec![format!(
+                "strategy must be one of: {}",
+                STRATEGY_NAMES.join(", ")
+            )],
         );
-    }
 
-    if let Err(RelativeHumidityInvalid(msg)) = indoor_humidity_result {
-        errors.insert(
-            String::from("indoor_measurement.relative_humidity"),
-            vec![msg.into()],
-        );
-    }
+        return Err(validation_problem(&errors, &uri.to_string()));
+    };
 
-    if let Err(TemperatureInvalid(msg)) = outdoor_temperature_result {
-        errors.insert(
-            String::from("outdoor_measurement.temperature"),
-            vec![msg.into()],
-        );
+    let open_window_result = strategy.evaluate(
+        &request.indoor_measurement,
+        &request.outdoor_measurement,
+        &StrategyContext::default(),
+    );
+
+    Ok(Json(open_window_result))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header::CONTENT_TYPE, Request, StatusCode},
+    };
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::app;
+
+    async fn post_open_window(body: &str) -> (StatusCode, Value) {
+        let request = Request::post("/open-window")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_owned()))
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
     }
 
-    if let Err(RelativeHumidityInvalid(msg)) = outdoor_humidity_result {
-        errors.insert(
-            String::from("outdoor_measurement.relative_humidity"),
-            vec![msg.into()],
-        );
+    macro_rules! post_open_window_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[tokio::test]
+                async fn $name() {
+                    let (body, (expected_status, expected_errors)) = $value;
+
+                    let (status, response) = post_open_window(body).await;
+
+                    assert_eq!(expected_status, status);
+                    assert_eq!(expected_errors, response["errors"]);
+                }
+             )*
+        }
     }
 
-    let problem = HttpApiProblem::new(StatusCode::BAD_REQUEST)
-        .title("Invalid request.")
-        .detail("Request validation failed.")
-        .type_url("validation-error")
-        .instance(uri.to_string())
-        .value("errors", &errors);
+    post_open_window_tests! {
+        valid_request: (
+            r#"{
+                "indoor_measurement": { "temperature": 18.0, "relative_humidity": 50 },
+                "outdoor_measurement": { "temperature": 0.0, "relative_humidity": 85 }
+            }"#,
+            (StatusCode::OK, Value::Null)
+        ),
+        invalid_field: (
+            r#"{
+                "indoor_measurement": { "temperature": 18.0, "relative_humidity": 50 },
+                "outdoor_measurement": { "temperature": 150.0, "relative_humidity": 85 }
+            }"#,
+            (
+                StatusCode::BAD_REQUEST,
+                json!({
+                    "outdoor_measurement.temperature": [
+                        "temperature must be at most 100, got 150"
+                    ]
+                })
+            )
+        ),
+        all_invalid_fields: (
+            r#"{
+                "indoor_measurement": { "temperature": -150.0, "relative_humidity": 0 },
+                "outdoor_measurement": { "temperature": 150.0, "relative_humidity": 101 }
+            }"#,
+            (
+                StatusCode::BAD_REQUEST,
+                json!({
+                    "indoor_measurement.temperature": [
+                        "temperature must be at least -100, got -150"
+                    ],
+                    "indoor_measurement.relative_humidity": [
+                        "relative humidity must be at least 1, got 0"
+                    ],
+                    "outdoor_measurement.temperature": [
+                        "temperature must be at most 100, got 150"
+                    ],
+                    "outdoor_measurement.relative_humidity": [
+                        "relative humidity must be at most 100, got 101"
+                    ]
+                })
+            )
+        ),
+        missing_field: (
+            r#"{ "indoor_measurement": { "temperature": 18.0, "relative_humidity": 50 } }"#,
+            (
+                StatusCode::BAD_REQUEST,
+                json!({ "request": ["missing field `outdoor_measurement`"] })
+            )
+        ),
+        unknown_strategy: (
+            r#"{
+                "indoor_measurement": { "temperature": 18.0, "relative_humidity": 50 },
+                "outdoor_measurement": { "temperature": 0.0, "relative_humidity": 85 },
+                "strategy": "unknown"
+            }"#,
+            (
+                StatusCode::BAD_REQUEST,
+                json!({
+                    "strategy": [
+                        "strategy must be one of: dew_point, absolute_humidity, comfort, energy"
+                    ]
+                })
+            )
+        ),
+        malformed_json: (
+            r#"{ "indoor_measurement": "#,
+            (StatusCode::BAD_REQUEST, Value::Null)
+        ),
+    }
 
-    Err(problem)
+    #[tokio::test]
+    async fn valid_request_result() {
+        let (_, response) = post_open_window(
+            r#"{
+                "indoor_measurement": { "temperature": 18.0, "relative_humidity": 50 },
+                "outdoor_measurement": { "temperature": 0.0, "relative_humidity": 85 }
+            }"#,
+        )
+        .await;
+
+        assert_eq!(json!(true), response["open_window"]);
+        assert_eq!(json!("open"), response["verdict"]);
+    }
 }
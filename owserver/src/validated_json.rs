@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use axum::{
+    async_trait,
+    body::{Bytes, HttpBody},
+    extract::{FromRequest, OriginalUri},
+    http::{header::CONTENT_TYPE, Request, StatusCode},
+    BoxError,
+};
+use http_api_problem::HttpApiProblem;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+/// Validation errors keyed by a path of the invalid field, e.g.
+/// `indoor_measurement.temperature`.
+pub type ValidationErrors = HashMap<String, Vec<String>>;
+
+/// JSON extractor reporting every invalid field of the request.
+///
+/// Validation is done by owlib while deserializing the request. Deserialization
+/// stops at the first invalid field, so it is retried without the fields
+/// reported so far, until the remaining ones are valid.
+pub struct ValidatedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S, B> FromRequest<S, B> for ValidatedJson<T>
+where
+    T: DeserializeOwned,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    type Rejection = HttpApiProblem;
+
+    async fn from_request(request: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let instance = match request.extensions().get::<OriginalUri>() {
+            Some(OriginalUri(uri)) => uri.to_string(),
+            None => request.uri().to_string(),
+        };
+
+        if !has_json_content_type(&request) {
+            return Err(invalid_request(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "Expected request with `Content-Type: application/json`",
+                &instance,
+            ));
+        }
+
+        let bytes = Bytes::from_request(request, state)
+            .await
+            .map_err(|rejection| {
+                invalid_request(rejection.status(), &rejection.body_text(), &instance)
+            })?;
+
+        let deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+
+        match serde_path_to_error::deserialize(deserializer) {
+            Ok(value) => Ok(Self(value)),
+            Err(error) if error.inner().is_data() => {
+                let errors = validation_errors::<T>(&bytes);
+
+                Err(validation_problem(&errors, &instance))
+            }
+            Err(error) => Err(invalid_request(
+                StatusCode::BAD_REQUEST,
+                &error.inner().to_string(),
+                &instance,
+            )),
+        }
+    }
+}
+
+/// Creates a problem reporting provided validation errors.
+pub fn validation_problem(errors: &ValidationErrors, instance: &str) -> HttpApiProblem {
+    HttpApiProblem::new(StatusCode::BAD_REQUEST)
+        .title("Invalid request.")
+        .detail("Request validation failed.")
+        .type_url("validation-error")
+        .instance(instance)
+        .value("errors", errors)
+}
+
+fn invalid_request(status: StatusCode, detail: &str, instance: &str) -> HttpApiProblem {
+    HttpApiProblem::new(status)
+        .title("Invalid request.")
+        .detail(detail)
+        .instance(instance)
+}
+
+fn has_json_content_type<B>(request: &Request<B>) -> bool {
+    request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("application/json"))
+}
+
+// Collects errors of a syntactically valid body, which failed deserialization.
+// Every reported field is removed before the next attempt. A missing field
+// reported because of an earlier removal is not an error of its own - the
+// object holding it is removed instead, so that its siblings get validated.
+fn validation_errors<T: DeserializeOwned>(bytes: &[u8]) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    let Ok(mut value) = serde_json::from_slice::<Value>(bytes) else {
+        return errors;
+    };
+    let mut removed: Vec<Vec<String>> = Vec::new();
+
+    while let Err(error) = serde_path_to_error::deserialize::<_, T>(&value) {
+        let Some(keys) = map_keys(error.path()) else {
+            errors.insert(error.path().to_string(), vec![error.inner().to_string()]);
+            break;
+        };
+
+        let caused_by_removal = removed
+            .iter()
+            .any(|removed| removed[..removed.len() - 1] == keys[..]);
+        if !caused_by_removal {
+            let field = if keys.is_empty() {
+                String::from("request")
+            } else {
+                error.path().to_string()
+            };
+            errors.insert(field, vec![error.inner().to_string()]);
+        }
+
+        if !remove(&mut value, &keys) {
+            break;
+        }
+        removed.push(keys);
+    }
+
+    errors
+}
+
+fn map_keys(path: &serde_path_to_error::Path) -> Option<Vec<String>> {
+    path.iter()
+        .map(|segment| match segment {
+            Segment::Map { key } => Some(key.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn remove(value: &mut Value, keys: &[String]) -> bool {
+    let Some((last, parents)) = keys.split_last() else {
+        return false;
+    };
+
+    let mut value = value;
+    for key in parents {
+        match value.get_mut(key) {
+            Some(child) => value = child,
+            None => return false,
+        }
+    }
+
+    value
+        .as_object_mut()
+        .is_some_and(|object| object.remove(last).is_some())
+}